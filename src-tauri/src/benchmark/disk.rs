@@ -0,0 +1,258 @@
+use serde::Serialize;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// 测试文件大小 (128 MB)
+const FILE_SIZE: u64 = 128 * 1024 * 1024;
+/// 顺序读写块大小 (1 MB)
+const SEQ_BLOCK: usize = 1024 * 1024;
+/// 随机读写块大小 (4 KB)
+const RAND_BLOCK: usize = 4096;
+/// 随机读写最大操作次数
+const RAND_OPS: usize = 4096;
+/// 随机读写单项最长耗时
+const RAND_TIME_LIMIT: Duration = Duration::from_secs(5);
+/// 两次测试的最短间隔，避免频繁测试加速 SSD 磨损
+const MIN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 磁盘基准测试结果
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskBenchmarkResult {
+    /// 测试目录
+    pub path: String,
+    /// 测试文件大小 (字节)
+    pub file_size: u64,
+    /// 顺序写入速度 (MB/s)
+    pub seq_write_mbps: f64,
+    /// 顺序读取速度 (MB/s)
+    pub seq_read_mbps: f64,
+    /// 4K 随机写入 (IOPS)
+    pub rand_write_iops: f64,
+    /// 4K 随机读取 (IOPS)
+    pub rand_read_iops: f64,
+    /// 总耗时 (毫秒)
+    pub duration_ms: u64,
+    /// 完成时间 (毫秒时间戳)
+    pub timestamp: i64,
+}
+
+pub struct DiskBenchmark {
+    last_run: Option<Instant>,
+    last_result: Option<DiskBenchmarkResult>,
+}
+
+impl DiskBenchmark {
+    /// 创建新的磁盘基准测试
+    pub fn new() -> Self {
+        Self {
+            last_run: None,
+            last_result: None,
+        }
+    }
+
+    /// 检查目录与频率限制并标记开始，同一时间只允许一次测试
+    ///
+    /// 目录无效时直接返回错误，不占用频率限制
+    pub fn try_start(&mut self, dir: &Path) -> Result<(), String> {
+        Self::check_dir(dir)?;
+
+        if let Some(last_run) = self.last_run {
+            let elapsed = last_run.elapsed();
+            if elapsed < MIN_INTERVAL {
+                return Err(format!(
+                    "Disk benchmark is rate limited, retry in {} seconds",
+                    (MIN_INTERVAL - elapsed).as_secs() + 1
+                ));
+            }
+        }
+
+        self.last_run = Some(Instant::now());
+        Ok(())
+    }
+
+    /// 保存最近一次测试结果
+    pub fn set_last_result(&mut self, result: DiskBenchmarkResult) {
+        self.last_result = Some(result);
+    }
+
+    /// 获取最近一次测试结果
+    pub fn last_result(&self) -> Option<DiskBenchmarkResult> {
+        self.last_result.clone()
+    }
+
+    /// 在指定目录运行基准测试（阻塞，耗时数秒）
+    pub fn run(dir: &Path) -> Result<DiskBenchmarkResult, String> {
+        Self::check_dir(dir)?;
+
+        let start = Instant::now();
+        let test_file = TestFile::new(dir);
+
+        let seq_write_mbps = Self::seq_write(&test_file.path)?;
+        drop_cache(&test_file.path);
+        let seq_read_mbps = Self::seq_read(&test_file.path)?;
+        let rand_write_iops = Self::rand_write(&test_file.path)?;
+        drop_cache(&test_file.path);
+        let rand_read_iops = Self::rand_read(&test_file.path)?;
+
+        Ok(DiskBenchmarkResult {
+            path: dir.to_string_lossy().to_string(),
+            file_size: FILE_SIZE,
+            seq_write_mbps,
+            seq_read_mbps,
+            rand_write_iops,
+            rand_read_iops,
+            duration_ms: start.elapsed().as_millis() as u64,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+        })
+    }
+
+    /// 检查测试目录是否存在
+    fn check_dir(dir: &Path) -> Result<(), String> {
+        if dir.is_dir() {
+            Ok(())
+        } else {
+            Err(format!("Not a directory: {}", dir.display()))
+        }
+    }
+
+    /// 顺序写入，计时包含 fsync
+    fn seq_write(path: &Path) -> Result<f64, String> {
+        let mut file = File::create(path).map_err(io_error)?;
+        let block = vec![0xA5u8; SEQ_BLOCK];
+
+        let start = Instant::now();
+        for _ in 0..FILE_SIZE / SEQ_BLOCK as u64 {
+            file.write_all(&block).map_err(io_error)?;
+        }
+        file.sync_all().map_err(io_error)?;
+
+        Ok(mb_per_sec(FILE_SIZE, start.elapsed()))
+    }
+
+    /// 顺序读取
+    fn seq_read(path: &Path) -> Result<f64, String> {
+        let mut file = File::open(path).map_err(io_error)?;
+        let mut block = vec![0u8; SEQ_BLOCK];
+
+        let start = Instant::now();
+        let mut total = 0u64;
+        loop {
+            let n = file.read(&mut block).map_err(io_error)?;
+            if n == 0 {
+                break;
+            }
+            total += n as u64;
+        }
+
+        Ok(mb_per_sec(total, start.elapsed()))
+    }
+
+    /// 4K 随机写入，每次写入后 fsync
+    fn rand_write(path: &Path) -> Result<f64, String> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .open(path)
+            .map_err(io_error)?;
+        let block = vec![0x5Au8; RAND_BLOCK];
+        let mut rng = XorShift::new();
+
+        let start = Instant::now();
+        let mut ops = 0;
+        while ops < RAND_OPS && start.elapsed() < RAND_TIME_LIMIT {
+            file.seek(SeekFrom::Start(rng.block_offset()))
+                .map_err(io_error)?;
+            file.write_all(&block).map_err(io_error)?;
+            file.sync_data().map_err(io_error)?;
+            ops += 1;
+        }
+
+        Ok(ops as f64 / start.elapsed().as_secs_f64())
+    }
+
+    /// 4K 随机读取
+    fn rand_read(path: &Path) -> Result<f64, String> {
+        let mut file = File::open(path).map_err(io_error)?;
+        let mut block = vec![0u8; RAND_BLOCK];
+        let mut rng = XorShift::new();
+
+        let start = Instant::now();
+        let mut ops = 0;
+        while ops < RAND_OPS && start.elapsed() < RAND_TIME_LIMIT {
+            file.seek(SeekFrom::Start(rng.block_offset()))
+                .map_err(io_error)?;
+            file.read_exact(&mut block).map_err(io_error)?;
+            ops += 1;
+        }
+
+        Ok(ops as f64 / start.elapsed().as_secs_f64())
+    }
+}
+
+impl Default for DiskBenchmark {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 测试文件，离开作用域时自动删除
+struct TestFile {
+    path: PathBuf,
+}
+
+impl TestFile {
+    fn new(dir: &Path) -> Self {
+        Self {
+            path: dir.join(format!(".skywidget-bench-{}.tmp", std::process::id())),
+        }
+    }
+}
+
+impl Drop for TestFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// 简单的 xorshift 伪随机数，仅用于生成随机偏移
+struct XorShift(u64);
+
+impl XorShift {
+    fn new() -> Self {
+        let seed = chrono::Utc::now().timestamp_nanos_opt().unwrap_or(1) as u64;
+        Self(seed | 1)
+    }
+
+    /// 返回文件内按 4K 对齐的随机偏移
+    fn block_offset(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % (FILE_SIZE / RAND_BLOCK as u64)) * RAND_BLOCK as u64
+    }
+}
+
+/// 从页缓存中移除测试文件，确保读取测试命中磁盘
+#[cfg(target_os = "linux")]
+fn drop_cache(path: &Path) {
+    use std::os::unix::io::AsRawFd;
+
+    if let Ok(file) = File::open(path) {
+        unsafe {
+            libc::posix_fadvise(file.as_raw_fd(), 0, 0, libc::POSIX_FADV_DONTNEED);
+        }
+    }
+}
+
+/// 其他平台无法可靠清除页缓存，读取结果可能偏高
+#[cfg(not(target_os = "linux"))]
+fn drop_cache(_path: &Path) {}
+
+fn mb_per_sec(bytes: u64, elapsed: Duration) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0) / elapsed.as_secs_f64()
+}
+
+fn io_error(e: std::io::Error) -> String {
+    format!("Disk benchmark I/O error: {}", e)
+}
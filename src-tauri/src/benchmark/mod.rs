@@ -0,0 +1,7 @@
+// 按需运行的基准测试
+pub mod disk;
+pub mod stress;
+
+// 重新导出便于使用
+pub use disk::DiskBenchmark;
+pub use stress::StressTest;
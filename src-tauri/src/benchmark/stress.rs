@@ -0,0 +1,259 @@
+use crate::monitors::{CpuMonitor, ThermalMonitor};
+use serde::Serialize;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 压力测试时长范围 (秒)
+const MIN_DURATION: u64 = 10;
+const MAX_DURATION: u64 = 600;
+/// 采样间隔
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+/// 负载结束后继续记录的冷却时间
+const COOLDOWN: Duration = Duration::from_secs(15);
+/// 内存负载上限 (1 GB)
+const MAX_MEMORY_LOAD: u64 = 1024 * 1024 * 1024;
+
+/// 压力测试采样点
+#[derive(Debug, Clone, Serialize)]
+pub struct StressSample {
+    /// 距测试开始的时间 (毫秒)
+    pub elapsed_ms: u64,
+    /// 是否处于负载阶段
+    pub under_load: bool,
+    /// CPU 使用率 (0-100)
+    pub cpu_usage: f32,
+    /// CPU 频率 (MHz)
+    pub frequency: u64,
+    /// 最高温度 (°C)
+    pub max_temperature: Option<f32>,
+}
+
+/// 压力测试温度报告
+#[derive(Debug, Clone, Serialize)]
+pub struct StressTestReport {
+    /// 负载持续时间 (秒)
+    pub duration_secs: u64,
+    /// 负载线程数
+    pub threads: usize,
+    /// 内存负载 (字节)
+    pub memory_load: u64,
+    /// 测试开始前的温度 (°C)
+    pub idle_temperature: Option<f32>,
+    /// 负载期间峰值温度 (°C)
+    pub peak_temperature: Option<f32>,
+    /// 峰值温升 (°C)
+    pub temperature_rise: Option<f32>,
+    /// 冷却结束时的温度 (°C)
+    pub cooldown_temperature: Option<f32>,
+    /// 负载期间平均频率 (MHz)
+    pub average_frequency: u64,
+    /// 负载期间最低频率 (MHz)
+    pub min_frequency: u64,
+    /// 负载期间最高频率 (MHz)
+    pub max_frequency: u64,
+    /// 负载期间频率最大降幅 (0-100)，明显降频说明散热不足
+    pub frequency_drop_percent: f64,
+    /// 所有采样点
+    pub samples: Vec<StressSample>,
+    /// 完成时间 (毫秒时间戳)
+    pub timestamp: i64,
+}
+
+pub struct StressTest {
+    running: bool,
+    last_report: Option<StressTestReport>,
+}
+
+impl StressTest {
+    /// 创建新的压力测试
+    pub fn new() -> Self {
+        Self {
+            running: false,
+            last_report: None,
+        }
+    }
+
+    /// 标记开始，同一时间只允许一次测试
+    pub fn try_start(&mut self) -> Result<(), String> {
+        if self.running {
+            return Err("A stress test is already running".to_string());
+        }
+
+        self.running = true;
+        Ok(())
+    }
+
+    /// 标记结束并保存报告
+    pub fn finish(&mut self, report: Option<StressTestReport>) {
+        self.running = false;
+        if report.is_some() {
+            self.last_report = report;
+        }
+    }
+
+    /// 获取最近一次测试报告
+    pub fn last_report(&self) -> Option<StressTestReport> {
+        self.last_report.clone()
+    }
+
+    /// 运行压力测试（阻塞，耗时为 duration 加冷却时间）
+    pub fn run(duration_secs: u64, available_memory: u64) -> StressTestReport {
+        let duration_secs = duration_secs.clamp(MIN_DURATION, MAX_DURATION);
+        let threads = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let memory_load = (available_memory / 4).min(MAX_MEMORY_LOAD);
+
+        // 使用独立的监控器，避免长时间占用全局监控器的锁
+        let mut cpu_monitor = CpuMonitor::new();
+        let mut thermal_monitor = ThermalMonitor::new();
+
+        let start = Instant::now();
+        let mut samples = Vec::new();
+        let mut sample = |under_load: bool, samples: &mut Vec<StressSample>| {
+            let cpu = cpu_monitor.get_info();
+            samples.push(StressSample {
+                elapsed_ms: start.elapsed().as_millis() as u64,
+                under_load,
+                cpu_usage: cpu.usage,
+                frequency: cpu.frequency,
+                max_temperature: thermal_monitor.get_info().max_temperature,
+            });
+        };
+
+        // 空载基线
+        thread::sleep(SAMPLE_INTERVAL);
+        sample(false, &mut samples);
+        let idle_temperature = samples[0].max_temperature;
+
+        // 负载阶段
+        let stop = Arc::new(AtomicBool::new(false));
+        let mut workers: Vec<_> = (0..threads)
+            .map(|_| {
+                let stop = Arc::clone(&stop);
+                thread::spawn(move || cpu_load(&stop))
+            })
+            .collect();
+        {
+            let stop = Arc::clone(&stop);
+            workers.push(thread::spawn(move || {
+                memory_load_worker(&stop, memory_load)
+            }));
+        }
+
+        let load_end = Instant::now() + Duration::from_secs(duration_secs);
+        while Instant::now() < load_end {
+            thread::sleep(SAMPLE_INTERVAL);
+            sample(true, &mut samples);
+        }
+
+        stop.store(true, Ordering::Relaxed);
+        for worker in workers {
+            let _ = worker.join();
+        }
+
+        // 冷却阶段
+        let cooldown_end = Instant::now() + COOLDOWN;
+        while Instant::now() < cooldown_end {
+            thread::sleep(SAMPLE_INTERVAL);
+            sample(false, &mut samples);
+        }
+
+        Self::build_report(
+            duration_secs,
+            threads,
+            memory_load,
+            idle_temperature,
+            samples,
+        )
+    }
+
+    /// 根据采样点生成报告
+    fn build_report(
+        duration_secs: u64,
+        threads: usize,
+        memory_load: u64,
+        idle_temperature: Option<f32>,
+        samples: Vec<StressSample>,
+    ) -> StressTestReport {
+        let loaded: Vec<&StressSample> = samples.iter().filter(|s| s.under_load).collect();
+
+        let peak_temperature = loaded
+            .iter()
+            .filter_map(|s| s.max_temperature)
+            .reduce(f32::max);
+        let temperature_rise = match (peak_temperature, idle_temperature) {
+            (Some(peak), Some(idle)) => Some(peak - idle),
+            _ => None,
+        };
+        let cooldown_temperature = samples.last().and_then(|s| s.max_temperature);
+
+        let frequencies: Vec<u64> = loaded
+            .iter()
+            .map(|s| s.frequency)
+            .filter(|&f| f > 0)
+            .collect();
+        let min_frequency = frequencies.iter().copied().min().unwrap_or(0);
+        let max_frequency = frequencies.iter().copied().max().unwrap_or(0);
+        let average_frequency = if frequencies.is_empty() {
+            0
+        } else {
+            frequencies.iter().sum::<u64>() / frequencies.len() as u64
+        };
+        let frequency_drop_percent = if max_frequency > 0 {
+            (max_frequency - min_frequency) as f64 / max_frequency as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        StressTestReport {
+            duration_secs,
+            threads,
+            memory_load,
+            idle_temperature,
+            peak_temperature,
+            temperature_rise,
+            cooldown_temperature,
+            average_frequency,
+            min_frequency,
+            max_frequency,
+            frequency_drop_percent,
+            samples,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+}
+
+impl Default for StressTest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// CPU 负载：持续进行浮点运算直到收到停止信号
+fn cpu_load(stop: &AtomicBool) {
+    let mut x = 1.0f64;
+    while !stop.load(Ordering::Relaxed) {
+        for _ in 0..10_000 {
+            x = black_box(x.sqrt() * 1.000_001 + 0.5);
+        }
+    }
+}
+
+/// 内存负载：分配缓冲区并反复按页写入
+fn memory_load_worker(stop: &AtomicBool, bytes: u64) {
+    const PAGE: usize = 4096;
+
+    let mut buffer = vec![0u8; bytes as usize];
+    let mut value = 0u8;
+    while !stop.load(Ordering::Relaxed) {
+        value = value.wrapping_add(1);
+        for offset in (0..buffer.len()).step_by(PAGE) {
+            buffer[offset] = value;
+        }
+        black_box(&buffer);
+    }
+}
@@ -0,0 +1,158 @@
+// 高频采集模式：临时以 1 秒间隔记录所有传感器数据到独立缓冲区
+use crate::monitors::thermal::ThermalZone;
+use crate::monitors::{CpuMonitor, MemoryMonitor, ThermalMonitor};
+use serde::Serialize;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// 采集时长上限 (分钟)
+const MAX_MINUTES: u64 = 60;
+/// 采样间隔
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 单个采样点
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureSample {
+    /// 采样时间 (毫秒时间戳)
+    pub timestamp: i64,
+    /// CPU 总体使用率 (0-100)
+    pub cpu_usage: f32,
+    /// 各核心使用率
+    pub core_usage: Vec<f32>,
+    /// CPU 频率 (MHz)
+    pub cpu_frequency: u64,
+    /// 内存使用率 (0-100)
+    pub memory_usage_percent: f64,
+    /// 交换分区使用率 (0-100)
+    pub swap_usage_percent: f64,
+    /// 各温区温度
+    pub thermal_zones: Vec<ThermalZone>,
+}
+
+/// 采集会话数据
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CaptureData {
+    /// 是否正在采集
+    pub active: bool,
+    /// 开始时间 (毫秒时间戳)
+    pub started_at: Option<i64>,
+    /// 计划结束时间 (毫秒时间戳)
+    pub ends_at: Option<i64>,
+    /// 采样点
+    pub samples: Vec<CaptureSample>,
+}
+
+pub struct Capture {
+    data: Arc<Mutex<CaptureData>>,
+    /// 采集线程及其停止信号，发送或丢弃 Sender 即通知线程退出
+    worker: Option<(Sender<()>, JoinHandle<()>)>,
+}
+
+impl Capture {
+    /// 创建新的采集器
+    pub fn new() -> Self {
+        Self {
+            data: Arc::new(Mutex::new(CaptureData::default())),
+            worker: None,
+        }
+    }
+
+    /// 开始采集，持续 minutes 分钟 (1-60)，会清空上一次的数据
+    pub fn start(&mut self, minutes: u64) -> Result<(), String> {
+        let minutes = minutes.clamp(1, MAX_MINUTES);
+        let duration = Duration::from_secs(minutes * 60);
+        let now = chrono::Utc::now().timestamp_millis();
+
+        // 回收已自然结束的上一次采集线程
+        if let Some((_, handle)) = self.worker.take_if(|(_, handle)| handle.is_finished()) {
+            let _ = handle.join();
+        }
+
+        {
+            let mut data = self.lock_data()?;
+            if data.active {
+                return Err("A capture is already running".to_string());
+            }
+
+            *data = CaptureData {
+                active: true,
+                started_at: Some(now),
+                ends_at: Some(now + duration.as_millis() as i64),
+                samples: Vec::with_capacity(duration.as_secs() as usize),
+            };
+        }
+
+        let (stop_tx, stop_rx) = mpsc::channel();
+        let data = Arc::clone(&self.data);
+        let handle = thread::spawn(move || Self::run(data, stop_rx, duration));
+        self.worker = Some((stop_tx, handle));
+
+        Ok(())
+    }
+
+    /// 提前停止采集，等待采集线程退出后返回，之后可立即重新开始
+    pub fn stop(&mut self) {
+        if let Some((stop_tx, handle)) = self.worker.take() {
+            let _ = stop_tx.send(());
+            let _ = handle.join();
+        }
+    }
+
+    /// 获取采集数据
+    pub fn get_data(&self) -> Result<CaptureData, String> {
+        self.lock_data().map(|data| data.clone())
+    }
+
+    fn lock_data(&self) -> Result<MutexGuard<'_, CaptureData>, String> {
+        self.data
+            .lock()
+            .map_err(|e| format!("Failed to lock capture data: {}", e))
+    }
+
+    /// 采集线程，使用独立的监控器避免占用全局监控器的锁
+    fn run(data: Arc<Mutex<CaptureData>>, stop: Receiver<()>, duration: Duration) {
+        let mut cpu_monitor = CpuMonitor::new();
+        let mut memory_monitor = MemoryMonitor::new();
+        let mut thermal_monitor = ThermalMonitor::new();
+
+        let end = Instant::now() + duration;
+        while Instant::now() < end {
+            // 等待下一个采样时刻，期间收到停止信号立即退出
+            match stop.recv_timeout(SAMPLE_INTERVAL) {
+                Err(RecvTimeoutError::Timeout) => {}
+                Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            let cpu = cpu_monitor.get_info();
+            let memory = memory_monitor.get_info();
+            let thermal = thermal_monitor.get_info();
+
+            let sample = CaptureSample {
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                cpu_usage: cpu.usage,
+                core_usage: cpu.core_usage,
+                cpu_frequency: cpu.frequency,
+                memory_usage_percent: memory.usage_percent,
+                swap_usage_percent: memory.swap_usage_percent,
+                thermal_zones: thermal.zones,
+            };
+
+            match data.lock() {
+                Ok(mut data) => data.samples.push(sample),
+                Err(_) => return,
+            }
+        }
+
+        if let Ok(mut data) = data.lock() {
+            data.active = false;
+        }
+    }
+}
+
+impl Default for Capture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,105 @@
+// 崩溃处理：panic 时将崩溃报告写入应用数据目录
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use sysinfo::System;
+
+/// 同一进程内的报告序号，多个线程在同一毫秒内 panic 时避免文件名冲突
+static REPORT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// 崩溃报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// 崩溃时间 (毫秒时间戳)
+    pub timestamp: i64,
+    /// 应用版本
+    pub version: String,
+    /// 操作系统
+    pub os: String,
+    /// 发生 panic 的线程
+    pub thread: String,
+    /// panic 信息
+    pub message: String,
+    /// panic 位置 (文件:行:列)
+    pub location: Option<String>,
+    /// 调用栈
+    pub backtrace: String,
+}
+
+/// 安装 panic hook，崩溃时先写入报告再交给默认处理器
+pub fn install_panic_hook(dir: PathBuf) {
+    let default_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unknown panic".to_string());
+
+        let report = CrashReport {
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            os: System::long_os_version().unwrap_or_else(|| std::env::consts::OS.to_string()),
+            thread: std::thread::current()
+                .name()
+                .unwrap_or("unnamed")
+                .to_string(),
+            message,
+            location: info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+            backtrace: Backtrace::force_capture().to_string(),
+        };
+
+        // 写入失败时不能再 panic，只能忽略
+        let _ = write_report(&dir, &report);
+
+        default_hook(info);
+    }));
+}
+
+/// 读取所有崩溃报告，按时间倒序
+pub fn list_reports(dir: &Path) -> Vec<CrashReport> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut reports: Vec<CrashReport> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .filter_map(|content| serde_json::from_str(&content).ok())
+        .collect();
+
+    reports.sort_by_key(|report| std::cmp::Reverse(report.timestamp));
+    reports
+}
+
+/// 删除所有崩溃报告
+pub fn clear_reports(dir: &Path) -> Result<(), String> {
+    if !dir.exists() {
+        return Ok(());
+    }
+
+    fs::remove_dir_all(dir).map_err(|e| format!("Failed to clear crash reports: {}", e))
+}
+
+/// 写入单个崩溃报告
+fn write_report(dir: &Path, report: &CrashReport) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+
+    // 多个实例共用同一报告目录，文件名带上进程号
+    let path = dir.join(format!(
+        "crash-{}-{}-{}.json",
+        report.timestamp,
+        std::process::id(),
+        REPORT_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let content = serde_json::to_string_pretty(report)?;
+    fs::write(path, content)
+}
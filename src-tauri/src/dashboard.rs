@@ -0,0 +1,64 @@
+// 仪表盘布局存储：按视图保存组件排列，持久化到应用数据目录
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::util::{load_json, save_json};
+
+/// 布局文件名
+const LAYOUTS_FILE: &str = "dashboard_layouts.json";
+
+/// 单个仪表盘组件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardWidget {
+    /// 组件 ID（同一视图内唯一）
+    pub id: String,
+    /// 组件类型 (如 cpu, memory, disk, chart)
+    pub widget_type: String,
+    /// 显示的指标
+    #[serde(default)]
+    pub metrics: Vec<String>,
+    /// 图表时间范围 (秒)
+    #[serde(default)]
+    pub time_range: Option<u64>,
+    /// 网格位置与大小
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// 单个视图的布局
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardLayout {
+    /// 视图名称
+    pub view: String,
+    /// 组件列表
+    pub widgets: Vec<DashboardWidget>,
+    /// 最后保存时间 (毫秒时间戳)
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+/// 读取指定视图的布局
+pub fn get_layout(dir: &Path, view: &str) -> Result<Option<DashboardLayout>, String> {
+    Ok(load_layouts(dir)?.remove(view))
+}
+
+/// 保存视图布局，覆盖同名视图
+pub fn save_layout(dir: &Path, mut layout: DashboardLayout) -> Result<(), String> {
+    if layout.view.trim().is_empty() {
+        return Err("Dashboard view name must not be empty".to_string());
+    }
+
+    let mut layouts = load_layouts(dir)?;
+    layout.updated_at = chrono::Utc::now().timestamp_millis();
+    layouts.insert(layout.view.clone(), layout);
+
+    save_json(dir, LAYOUTS_FILE, &layouts, "dashboard layouts")
+}
+
+/// 读取所有视图布局，文件不存在时返回空
+fn load_layouts(dir: &Path) -> Result<HashMap<String, DashboardLayout>, String> {
+    load_json(dir, LAYOUTS_FILE, "dashboard layouts").map(Option::unwrap_or_default)
+}
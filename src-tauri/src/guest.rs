@@ -0,0 +1,155 @@
+// 访客模式：开启后禁用所有修改系统或配置的命令，仪表盘保持可见，输入本地 PIN 才能退出
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::util::{load_json, save_json};
+
+/// 访客模式配置文件名
+const GUEST_FILE: &str = "guest_mode.json";
+/// PIN 长度范围
+const MIN_PIN_LEN: usize = 4;
+const MAX_PIN_LEN: usize = 12;
+/// 连续输错该次数后暂时锁定
+const MAX_FAILED_ATTEMPTS: u32 = 5;
+const LOCKOUT: Duration = Duration::from_secs(60);
+
+/// 访客模式状态
+#[derive(Debug, Clone, Serialize)]
+pub struct GuestModeStatus {
+    /// 是否处于访客模式
+    pub enabled: bool,
+    /// 是否已设置 PIN
+    pub pin_set: bool,
+}
+
+/// 持久化内容，只保存加盐后的 PIN 哈希
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GuestModeFile {
+    enabled: bool,
+    pin_hash: Option<String>,
+    salt: String,
+}
+
+#[derive(Default)]
+pub struct GuestMode {
+    file: GuestModeFile,
+    failed_attempts: u32,
+    locked_until: Option<Instant>,
+}
+
+impl GuestMode {
+    /// 从应用数据目录读取，文件不存在或损坏时为关闭状态
+    pub fn load(dir: &Path) -> Self {
+        let file = load_json(dir, GUEST_FILE, "guest mode")
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
+        Self {
+            file,
+            ..Self::default()
+        }
+    }
+
+    /// 当前状态
+    pub fn status(&self) -> GuestModeStatus {
+        GuestModeStatus {
+            enabled: self.file.enabled,
+            pin_set: self.file.pin_hash.is_some(),
+        }
+    }
+
+    /// 访客模式下拒绝执行修改类命令
+    pub fn ensure_not_guest(&self) -> Result<(), String> {
+        if self.file.enabled {
+            Err("This action is disabled in guest mode".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 开启访客模式；首次开启必须设置 PIN，传入新 PIN 时会替换旧 PIN
+    pub fn enable(&mut self, dir: &Path, pin: Option<&str>) -> Result<(), String> {
+        if self.file.enabled {
+            return Ok(());
+        }
+
+        match pin {
+            Some(pin) => {
+                validate_pin(pin)?;
+                self.file.salt = new_salt();
+                self.file.pin_hash = Some(hash_pin(&self.file.salt, pin));
+            }
+            None if self.file.pin_hash.is_none() => {
+                return Err("A PIN is required to enable guest mode".to_string());
+            }
+            None => {}
+        }
+
+        self.file.enabled = true;
+        self.save(dir)
+    }
+
+    /// 验证 PIN 后退出访客模式
+    pub fn disable(&mut self, dir: &Path, pin: &str) -> Result<(), String> {
+        if !self.file.enabled {
+            return Ok(());
+        }
+
+        if let Some(until) = self.locked_until {
+            if Instant::now() < until {
+                return Err("Too many incorrect PIN attempts, try again later".to_string());
+            }
+            self.locked_until = None;
+        }
+
+        if self.file.pin_hash.as_deref() != Some(hash_pin(&self.file.salt, pin).as_str()) {
+            self.failed_attempts += 1;
+            if self.failed_attempts >= MAX_FAILED_ATTEMPTS {
+                self.failed_attempts = 0;
+                self.locked_until = Some(Instant::now() + LOCKOUT);
+            }
+            return Err("Incorrect PIN".to_string());
+        }
+
+        self.failed_attempts = 0;
+        self.file.enabled = false;
+        self.save(dir)
+    }
+
+    fn save(&self, dir: &Path) -> Result<(), String> {
+        save_json(dir, GUEST_FILE, &self.file, "guest mode")
+    }
+}
+
+/// PIN 必须为 4-12 位数字
+fn validate_pin(pin: &str) -> Result<(), String> {
+    let valid_len = (MIN_PIN_LEN..=MAX_PIN_LEN).contains(&pin.len());
+    if valid_len && pin.chars().all(|c| c.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "PIN must be {}-{} digits",
+            MIN_PIN_LEN, MAX_PIN_LEN
+        ))
+    }
+}
+
+fn hash_pin(salt: &str, pin: &str) -> String {
+    let digest = Sha256::new()
+        .chain_update(salt.as_bytes())
+        .chain_update(pin.as_bytes())
+        .finalize();
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// 盐值只需避免不同机器得到相同哈希，时间与进程号足够
+fn new_salt() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("{:x}{:x}", nanos, std::process::id())
+}
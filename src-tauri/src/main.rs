@@ -1,18 +1,53 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod benchmark;
+mod capture;
+mod crash;
+mod dashboard;
+mod guest;
 mod monitors;
+mod power;
+mod reachability;
+mod util;
 
-use monitors::{CpuMonitor, DiskMonitor, MemoryMonitor};
+use benchmark::{DiskBenchmark, StressTest};
+use capture::Capture;
+use monitors::{
+    BatteryMonitor, CpuMonitor, DiskMonitor, GpuMonitor, HwmonMonitor, InventoryMonitor,
+    MemoryMonitor, NetworkMonitor, PowerStateMonitor, PressureMonitor, ProcessMonitor,
+    SmartMonitor, ThermalMonitor, VmMonitor,
+};
+use std::path::PathBuf;
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use sysinfo::System;
-use tauri::State;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+// 电源状态后台采样间隔
+const POWER_STATE_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
 
 // 全局状态管理
 pub struct AppState {
     cpu_monitor: Mutex<CpuMonitor>,
     memory_monitor: Mutex<MemoryMonitor>,
     disk_monitor: Mutex<DiskMonitor>,
+    pressure_monitor: Mutex<PressureMonitor>,
+    thermal_monitor: Mutex<ThermalMonitor>,
+    gpu_monitor: Mutex<GpuMonitor>,
+    hwmon_monitor: Mutex<HwmonMonitor>,
+    disk_benchmark: Mutex<DiskBenchmark>,
+    stress_test: Mutex<StressTest>,
+    capture: Mutex<Capture>,
+    vm_monitor: Mutex<VmMonitor>,
+    network_monitor: Mutex<NetworkMonitor>,
+    process_monitor: Mutex<ProcessMonitor>,
+    hardware_cache: Mutex<Option<(Instant, serde_json::Value)>>,
+    guest_mode: Mutex<guest::GuestMode>,
+    smart_monitor: Mutex<SmartMonitor>,
+    power_state_monitor: Mutex<PowerStateMonitor>,
+    battery_monitor: Mutex<BatteryMonitor>,
+    inventory_monitor: Mutex<InventoryMonitor>,
 }
 
 // 简单的问候命令
@@ -68,19 +103,586 @@ fn get_disk_info(state: State<AppState>) -> Result<monitors::disk::DisksInfo, St
         .map(|mut monitor| monitor.get_info())
 }
 
+// 获取磁盘汇总的排除规则
+#[tauri::command]
+fn get_disk_exclusions(state: State<AppState>) -> Result<Vec<String>, String> {
+    state
+        .disk_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock disk monitor: {}", e))
+        .map(|monitor| monitor.exclusions().to_vec())
+}
+
+// 设置磁盘汇总的排除规则（以 / 或盘符如 D: 开头匹配挂载点，其余匹配文件系统类型，支持 * 和 ?）
+#[tauri::command]
+fn set_disk_exclusions(
+    app: AppHandle,
+    state: State<AppState>,
+    patterns: Vec<String>,
+) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    let mut monitor = state
+        .disk_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock disk monitor: {}", e))?;
+    monitor.set_exclusions(patterns);
+    monitors::disk::save_exclusions(&app_data_dir(&app)?, monitor.exclusions())
+}
+
+// 获取资源压力信息 (Linux PSI)
+#[tauri::command]
+fn get_pressure_info(state: State<AppState>) -> Result<monitors::pressure::PressureInfo, String> {
+    state
+        .pressure_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock pressure monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
+// 获取温度信息（温区与 SoC 状态）
+#[tauri::command]
+fn get_thermal_info(state: State<AppState>) -> Result<monitors::thermal::ThermalInfo, String> {
+    state
+        .thermal_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock thermal monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
+// 获取温度最高的 n 个传感器（默认 5 个，包含 thermal 温区与 hwmon 温度通道，Windows 下含 LHM 温度）
+#[tauri::command]
+async fn get_hottest_sensors(
+    state: State<'_, AppState>,
+    n: Option<usize>,
+) -> Result<Vec<monitors::thermal::HotSensor>, String> {
+    refresh_lhm(&state).await?;
+    let channels = state
+        .hwmon_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock hwmon monitor: {}", e))?
+        .temperature_channels();
+
+    state
+        .thermal_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock thermal monitor: {}", e))
+        .map(|mut monitor| monitor.get_hottest(n.unwrap_or(5), channels))
+}
+
+// 获取 GPU 信息（Jetson 上 tegrastats 需等待一次采样，在后台线程执行）
+#[tauri::command]
+async fn get_gpu_info(state: State<'_, AppState>) -> Result<monitors::gpu::GpuInfo, String> {
+    let needs_tegrastats = state
+        .gpu_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock GPU monitor: {}", e))?
+        .needs_tegrastats();
+
+    if needs_tegrastats {
+        let line = tauri::async_runtime::spawn_blocking(GpuMonitor::collect_tegrastats)
+            .await
+            .map_err(|e| format!("tegrastats task failed: {}", e))?;
+        state
+            .gpu_monitor
+            .lock()
+            .map_err(|e| format!("Failed to lock GPU monitor: {}", e))?
+            .update_tegrastats(line);
+    }
+
+    state
+        .gpu_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock GPU monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
+// 刷新缓存的 LHM 查询结果（Windows 下 LHM 查询较慢，在后台线程执行）
+async fn refresh_lhm(state: &State<'_, AppState>) -> Result<(), String> {
+    let needs_refresh = state
+        .hwmon_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock hwmon monitor: {}", e))?
+        .needs_lhm_refresh();
+
+    if needs_refresh {
+        let chips = tauri::async_runtime::spawn_blocking(HwmonMonitor::collect_lhm)
+            .await
+            .map_err(|e| format!("LibreHardwareMonitor query task failed: {}", e))?;
+        state
+            .hwmon_monitor
+            .lock()
+            .map_err(|e| format!("Failed to lock hwmon monitor: {}", e))?
+            .update_lhm(chips);
+    }
+
+    Ok(())
+}
+
+// 获取 hwmon 传感器芯片及驱动状态（LHM 查询结果缓存）
+#[tauri::command]
+async fn get_hwmon_info(state: State<'_, AppState>) -> Result<monitors::hwmon::HwmonInfo, String> {
+    refresh_lhm(&state).await?;
+
+    state
+        .hwmon_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock hwmon monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
+// 加载 Super-I/O 传感器驱动（modprobe 在后台线程执行）
+#[tauri::command]
+async fn load_sensor_driver(state: State<'_, AppState>, module: String) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    tauri::async_runtime::spawn_blocking(move || HwmonMonitor::load_driver(&module))
+        .await
+        .map_err(|e| format!("modprobe task failed: {}", e))?
+}
+
+// 运行磁盘基准测试（限频，在后台线程执行）
+#[tauri::command]
+async fn run_disk_benchmark(
+    state: State<'_, AppState>,
+    path: Option<String>,
+) -> Result<benchmark::disk::DiskBenchmarkResult, String> {
+    ensure_not_guest(&state)?;
+    let dir = path.map(PathBuf::from).unwrap_or_else(std::env::temp_dir);
+    state
+        .disk_benchmark
+        .lock()
+        .map_err(|e| format!("Failed to lock disk benchmark: {}", e))?
+        .try_start(&dir)?;
+
+    let result = tauri::async_runtime::spawn_blocking(move || DiskBenchmark::run(&dir))
+        .await
+        .map_err(|e| format!("Disk benchmark task failed: {}", e))??;
+
+    state
+        .disk_benchmark
+        .lock()
+        .map_err(|e| format!("Failed to lock disk benchmark: {}", e))?
+        .set_last_result(result.clone());
+
+    Ok(result)
+}
+
+// 获取最近一次磁盘基准测试结果
+#[tauri::command]
+fn get_last_disk_benchmark(
+    state: State<AppState>,
+) -> Result<Option<benchmark::disk::DiskBenchmarkResult>, String> {
+    state
+        .disk_benchmark
+        .lock()
+        .map_err(|e| format!("Failed to lock disk benchmark: {}", e))
+        .map(|benchmark| benchmark.last_result())
+}
+
+// 运行 CPU/内存压力测试，同时记录温度和频率变化
+#[tauri::command]
+async fn run_stress_test(
+    state: State<'_, AppState>,
+    duration: u64,
+) -> Result<benchmark::stress::StressTestReport, String> {
+    ensure_not_guest(&state)?;
+    let available_memory = state
+        .memory_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock memory monitor: {}", e))?
+        .get_info()
+        .available;
+
+    state
+        .stress_test
+        .lock()
+        .map_err(|e| format!("Failed to lock stress test: {}", e))?
+        .try_start()?;
+
+    let result =
+        tauri::async_runtime::spawn_blocking(move || StressTest::run(duration, available_memory))
+            .await
+            .map_err(|e| format!("Stress test task failed: {}", e));
+
+    state
+        .stress_test
+        .lock()
+        .map_err(|e| format!("Failed to lock stress test: {}", e))?
+        .finish(result.as_ref().ok().cloned());
+
+    result
+}
+
+// 获取最近一次压力测试报告
+#[tauri::command]
+fn get_last_stress_test(
+    state: State<AppState>,
+) -> Result<Option<benchmark::stress::StressTestReport>, String> {
+    state
+        .stress_test
+        .lock()
+        .map_err(|e| format!("Failed to lock stress test: {}", e))
+        .map(|stress_test| stress_test.last_report())
+}
+
+// 开始高频采集（1 秒间隔，持续 minutes 分钟）
+#[tauri::command]
+fn start_capture(state: State<AppState>, minutes: u64) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    state
+        .capture
+        .lock()
+        .map_err(|e| format!("Failed to lock capture: {}", e))?
+        .start(minutes)
+}
+
+// 提前停止高频采集
+#[tauri::command]
+fn stop_capture(state: State<AppState>) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    state
+        .capture
+        .lock()
+        .map_err(|e| format!("Failed to lock capture: {}", e))
+        .map(|mut capture| capture.stop())
+}
+
+// 获取高频采集数据
+#[tauri::command]
+fn get_capture(state: State<AppState>) -> Result<capture::CaptureData, String> {
+    state
+        .capture
+        .lock()
+        .map_err(|e| format!("Failed to lock capture: {}", e))?
+        .get_data()
+}
+
+// 获取 CPU 调速器 / 电源计划（powercfg 在后台线程执行）
+#[tauri::command]
+async fn get_power_plans() -> Result<power::PowerPlanInfo, String> {
+    tauri::async_runtime::spawn_blocking(power::get_power_plans)
+        .await
+        .map_err(|e| format!("Power plan task failed: {}", e))
+}
+
+// 切换 CPU 调速器 / 电源计划（需要管理员权限）
+#[tauri::command]
+async fn set_power_plan(state: State<'_, AppState>, id: String) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    tauri::async_runtime::spawn_blocking(move || power::set_power_plan(&id))
+        .await
+        .map_err(|e| format!("Power plan task failed: {}", e))?
+}
+
+// 应用数据目录
+fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))
+}
+
+// 获取仪表盘视图布局
+#[tauri::command]
+fn get_dashboard_layout(
+    app: AppHandle,
+    view: String,
+) -> Result<Option<dashboard::DashboardLayout>, String> {
+    dashboard::get_layout(&app_data_dir(&app)?, &view)
+}
+
+// 保存仪表盘视图布局
+#[tauri::command]
+fn save_dashboard_layout(
+    app: AppHandle,
+    state: State<AppState>,
+    layout: dashboard::DashboardLayout,
+) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    dashboard::save_layout(&app_data_dir(&app)?, layout)
+}
+
+// 崩溃报告目录
+fn crash_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app_data_dir(app).map(|dir| dir.join("crashes"))
+}
+
+// 获取已保存的崩溃报告
+#[tauri::command]
+fn get_crash_reports(app: AppHandle) -> Result<Vec<crash::CrashReport>, String> {
+    Ok(crash::list_reports(&crash_dir(&app)?))
+}
+
+// 清除已保存的崩溃报告
+#[tauri::command]
+fn clear_crash_reports(app: AppHandle, state: State<AppState>) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    crash::clear_reports(&crash_dir(&app)?)
+}
+
+// 获取 libvirt 虚拟机信息（virsh 可能因 libvirtd 无响应而阻塞，在后台线程执行）
+#[tauri::command]
+async fn get_vm_info(state: State<'_, AppState>) -> Result<monitors::vm::VmInfo, String> {
+    let needs_refresh = state
+        .vm_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock VM monitor: {}", e))?
+        .needs_refresh();
+
+    if needs_refresh {
+        let output = tauri::async_runtime::spawn_blocking(VmMonitor::collect)
+            .await
+            .map_err(|e| format!("virsh task failed: {}", e))?;
+        state
+            .vm_monitor
+            .lock()
+            .map_err(|e| format!("Failed to lock VM monitor: {}", e))?
+            .update(output);
+    }
+
+    state
+        .vm_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock VM monitor: {}", e))
+        .map(|monitor| monitor.get_info())
+}
+
+// 获取网卡链路状态与速率
+#[tauri::command]
+fn get_network_info(state: State<AppState>) -> Result<monitors::network::NetworkInfo, String> {
+    state
+        .network_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock network monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
+// 设置网卡期望速率 (Mbps)，speed 为空时清除
+#[tauri::command]
+fn set_expected_link_speed(
+    app: AppHandle,
+    state: State<AppState>,
+    interface: String,
+    speed: Option<u64>,
+) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    let mut monitor = state
+        .network_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock network monitor: {}", e))?;
+    monitor.set_expected_speed(&interface, speed);
+    monitors::network::save_expected_speeds(&app_data_dir(&app)?, monitor.expected_speeds())
+}
+
+// 探测配置目标的网络可达性（延迟与丢包）
+#[tauri::command]
+async fn run_reachability_probe(app: AppHandle) -> Result<reachability::ReachabilityInfo, String> {
+    let targets = reachability::load_targets(&app_data_dir(&app)?)?;
+    tauri::async_runtime::spawn_blocking(move || reachability::probe_all(&targets))
+        .await
+        .map_err(|e| format!("Reachability probe task failed: {}", e))
+}
+
+// 获取可达性探测目标
+#[tauri::command]
+fn get_reachability_targets(app: AppHandle) -> Result<Vec<reachability::ProbeTarget>, String> {
+    reachability::load_targets(&app_data_dir(&app)?)
+}
+
+// 保存可达性探测目标
+#[tauri::command]
+fn set_reachability_targets(
+    app: AppHandle,
+    state: State<AppState>,
+    targets: Vec<reachability::ProbeTarget>,
+) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    reachability::save_targets(&app_data_dir(&app)?, &targets)
+}
+
+// 获取固定进程的 CPU / 内存占用
+#[tauri::command]
+fn get_process_info(state: State<AppState>) -> Result<monitors::process::ProcessInfo, String> {
+    state
+        .process_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock process monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
+// 设置需要跟踪的进程名称
+#[tauri::command]
+fn set_pinned_processes(
+    app: AppHandle,
+    state: State<AppState>,
+    names: Vec<String>,
+) -> Result<(), String> {
+    ensure_not_guest(&state)?;
+    let mut monitor = state
+        .process_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock process monitor: {}", e))?;
+    monitor.set_pinned(names);
+    monitors::process::save_pinned(&app_data_dir(&app)?, monitor.pinned())
+}
+
+// 获取磁盘 SMART 健康信息（缓存 5 分钟，过期时在后台线程刷新）
+#[tauri::command]
+async fn get_smart_info(state: State<'_, AppState>) -> Result<monitors::smart::SmartInfo, String> {
+    let needs_refresh = state
+        .smart_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock SMART monitor: {}", e))?
+        .needs_refresh();
+
+    if needs_refresh {
+        let devices = tauri::async_runtime::spawn_blocking(SmartMonitor::collect)
+            .await
+            .map_err(|e| format!("SMART collection task failed: {}", e))?;
+        state
+            .smart_monitor
+            .lock()
+            .map_err(|e| format!("Failed to lock SMART monitor: {}", e))?
+            .update(devices);
+    }
+
+    state
+        .smart_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock SMART monitor: {}", e))
+        .map(|monitor| monitor.get_info())
+}
+
+// 获取后台采样的交流电源、合盖与扩展坞状态及其变化记录
+#[tauri::command]
+fn get_power_state(
+    state: State<AppState>,
+) -> Result<monitors::power_state::PowerStateInfo, String> {
+    state
+        .power_state_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock power state monitor: {}", e))
+        .map(|monitor| monitor.get_info())
+}
+
+// 定期采样电源、合盖与扩展坞状态，每次变化发出 power-state-changed 事件
+//
+// 这是唯一调用 sample() 的地方，get_power_state 只读取已记录的状态，避免轮询抢先消耗变化
+fn spawn_power_state_sampler(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(POWER_STATE_SAMPLE_INTERVAL);
+
+        let state = app.state::<AppState>();
+        let events = match state.power_state_monitor.lock() {
+            Ok(mut monitor) => monitor.sample(),
+            Err(e) => {
+                // 其他线程持锁时 panic，监控器数据仍然有效，恢复后继续采样
+                eprintln!("Power state monitor lock poisoned, recovering: {}", e);
+                let events = e.into_inner().sample();
+                state.power_state_monitor.clear_poison();
+                events
+            }
+        };
+        for event in events {
+            let _ = app.emit("power-state-changed", event);
+        }
+    });
+}
+
+// 访客模式下拒绝修改类命令
+fn ensure_not_guest(state: &AppState) -> Result<(), String> {
+    state
+        .guest_mode
+        .lock()
+        .map_err(|e| format!("Failed to lock guest mode: {}", e))?
+        .ensure_not_guest()
+}
+
+// 获取访客模式状态
+#[tauri::command]
+fn get_guest_mode(state: State<AppState>) -> Result<guest::GuestModeStatus, String> {
+    state
+        .guest_mode
+        .lock()
+        .map_err(|e| format!("Failed to lock guest mode: {}", e))
+        .map(|guest_mode| guest_mode.status())
+}
+
+// 开启访客模式（首次开启需设置 PIN）
+#[tauri::command]
+fn enable_guest_mode(
+    app: AppHandle,
+    state: State<AppState>,
+    pin: Option<String>,
+) -> Result<(), String> {
+    state
+        .guest_mode
+        .lock()
+        .map_err(|e| format!("Failed to lock guest mode: {}", e))?
+        .enable(&app_data_dir(&app)?, pin.as_deref())
+}
+
+// 输入 PIN 退出访客模式
+#[tauri::command]
+fn disable_guest_mode(app: AppHandle, state: State<AppState>, pin: String) -> Result<(), String> {
+    state
+        .guest_mode
+        .lock()
+        .map_err(|e| format!("Failed to lock guest mode: {}", e))?
+        .disable(&app_data_dir(&app)?, &pin)
+}
+
+// 获取电池信息
+#[tauri::command]
+fn get_battery_info(state: State<AppState>) -> Result<monitors::battery::BatteryInfo, String> {
+    state
+        .battery_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock battery monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
+// 获取硬件清单及其变化记录
+#[tauri::command]
+fn get_hardware_inventory(
+    state: State<AppState>,
+) -> Result<monitors::inventory::InventoryInfo, String> {
+    state
+        .inventory_monitor
+        .lock()
+        .map_err(|e| format!("Failed to lock inventory monitor: {}", e))
+        .map(|mut monitor| monitor.get_info())
+}
+
 // 获取所有硬件信息（一次性获取全部数据）
+// 传入 max_age_ms 时，快照未超过该时长则直接返回缓存；不传则总是重新采集
 #[tauri::command]
-fn get_all_hardware_info(state: State<AppState>) -> Result<serde_json::Value, String> {
+fn get_all_hardware_info(
+    state: State<AppState>,
+    max_age_ms: Option<u64>,
+) -> Result<serde_json::Value, String> {
+    // 刷新期间持有缓存锁，同时到达的请求会等待并复用这次刷新的结果
+    let mut cache = state
+        .hardware_cache
+        .lock()
+        .map_err(|e| format!("Failed to lock hardware cache: {}", e))?;
+    if let (Some(max_age), Some((taken_at, snapshot))) = (max_age_ms, cache.as_ref()) {
+        if taken_at.elapsed() <= Duration::from_millis(max_age) {
+            return Ok(snapshot.clone());
+        }
+    }
+
     let cpu_info = get_cpu_info(state.clone())?;
     let memory_info = get_memory_info(state.clone())?;
-    let disk_info = get_disk_info(state)?;
+    let disk_info = get_disk_info(state.clone())?;
 
-    Ok(serde_json::json!({
+    let snapshot = serde_json::json!({
         "cpu": cpu_info,
         "memory": memory_info,
         "disk": disk_info,
         "timestamp": chrono::Utc::now().timestamp_millis(),
-    }))
+    });
+    *cache = Some((Instant::now(), snapshot.clone()));
+
+    Ok(snapshot)
 }
 
 fn main() {
@@ -89,18 +691,105 @@ fn main() {
         cpu_monitor: Mutex::new(CpuMonitor::new()),
         memory_monitor: Mutex::new(MemoryMonitor::new()),
         disk_monitor: Mutex::new(DiskMonitor::new()),
+        pressure_monitor: Mutex::new(PressureMonitor::new()),
+        thermal_monitor: Mutex::new(ThermalMonitor::new()),
+        gpu_monitor: Mutex::new(GpuMonitor::new()),
+        hwmon_monitor: Mutex::new(HwmonMonitor::new()),
+        disk_benchmark: Mutex::new(DiskBenchmark::new()),
+        stress_test: Mutex::new(StressTest::new()),
+        capture: Mutex::new(Capture::new()),
+        vm_monitor: Mutex::new(VmMonitor::new()),
+        network_monitor: Mutex::new(NetworkMonitor::new()),
+        process_monitor: Mutex::new(ProcessMonitor::new()),
+        hardware_cache: Mutex::new(None),
+        guest_mode: Mutex::new(guest::GuestMode::default()),
+        smart_monitor: Mutex::new(SmartMonitor::new()),
+        power_state_monitor: Mutex::new(PowerStateMonitor::new()),
+        battery_monitor: Mutex::new(BatteryMonitor::new()),
+        inventory_monitor: Mutex::new(InventoryMonitor::new()),
     };
 
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .manage(app_state)
+        .setup(|app| {
+            // 安装崩溃处理器，panic 时将报告写入应用数据目录
+            crash::install_panic_hook(crash_dir(app.handle())?);
+
+            // 恢复访客模式，避免重启应用即可绕过
+            let guest_mode = guest::GuestMode::load(&app_data_dir(app.handle())?);
+            if let Ok(mut state) = app.state::<AppState>().guest_mode.lock() {
+                *state = guest_mode;
+            }
+
+            // 加载磁盘排除规则
+            let exclusions = monitors::disk::load_exclusions(&app_data_dir(app.handle())?)
+                .unwrap_or_else(|_| monitors::disk::default_exclusions());
+            if let Ok(mut monitor) = app.state::<AppState>().disk_monitor.lock() {
+                monitor.set_exclusions(exclusions);
+            }
+
+            // 加载各网卡的期望速率配置
+            let speeds = monitors::network::load_expected_speeds(&app_data_dir(app.handle())?)
+                .unwrap_or_default();
+            if let Ok(mut monitor) = app.state::<AppState>().network_monitor.lock() {
+                monitor.set_expected_speeds(speeds);
+            }
+
+            // 加载固定进程列表
+            let pinned =
+                monitors::process::load_pinned(&app_data_dir(app.handle())?).unwrap_or_default();
+            if let Ok(mut monitor) = app.state::<AppState>().process_monitor.lock() {
+                monitor.set_pinned(pinned);
+            }
+
+            // 后台采样电源状态，状态变化时立即通知前端，无需等待轮询
+            spawn_power_state_sampler(app.handle().clone());
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             greet,
             get_system_info,
             get_cpu_info,
             get_memory_info,
             get_disk_info,
+            get_disk_exclusions,
+            set_disk_exclusions,
+            get_pressure_info,
+            get_thermal_info,
+            get_hottest_sensors,
+            get_gpu_info,
+            get_hwmon_info,
+            load_sensor_driver,
+            get_vm_info,
+            get_network_info,
+            set_expected_link_speed,
+            run_reachability_probe,
+            get_reachability_targets,
+            set_reachability_targets,
+            get_process_info,
+            set_pinned_processes,
+            get_smart_info,
+            get_power_state,
+            get_guest_mode,
+            enable_guest_mode,
+            disable_guest_mode,
+            get_battery_info,
+            get_hardware_inventory,
             get_all_hardware_info,
+            run_disk_benchmark,
+            get_last_disk_benchmark,
+            run_stress_test,
+            get_last_stress_test,
+            start_capture,
+            stop_capture,
+            get_capture,
+            get_power_plans,
+            set_power_plan,
+            get_dashboard_layout,
+            save_dashboard_layout,
+            get_crash_reports,
+            clear_crash_reports,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,204 @@
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::util::read_trimmed;
+
+/// 电量低于该值且未充电时给出警告 (%)
+const LOW_CHARGE_PERCENT: f32 = 20.0;
+/// 健康度低于该值时给出警告 (%)
+const DEGRADED_HEALTH_PERCENT: f32 = 80.0;
+
+/// 单块电池信息
+#[derive(Debug, Clone, Serialize)]
+pub struct Battery {
+    /// 设备名 (如 BAT0)
+    pub name: String,
+    /// 厂商
+    pub manufacturer: Option<String>,
+    /// 型号
+    pub model: Option<String>,
+    /// 电池化学类型 (如 Li-ion)
+    pub technology: Option<String>,
+    /// 当前电量 (%)
+    pub charge_percent: Option<f32>,
+    /// 充电状态: Charging, Discharging, Full, Not charging, Unknown
+    pub status: String,
+    /// 是否正在充电
+    pub charging: bool,
+    /// 设计容量 (Wh)
+    pub design_capacity_wh: Option<f32>,
+    /// 当前满充容量 (Wh)
+    pub full_capacity_wh: Option<f32>,
+    /// 健康度：满充容量 / 设计容量 (%)
+    pub health_percent: Option<f32>,
+    /// 放电功率 (W)，仅放电时有值
+    pub discharge_rate_watts: Option<f32>,
+    /// 按当前放电功率估算的剩余时间 (分钟)
+    pub time_remaining_minutes: Option<u32>,
+    /// 循环次数
+    pub cycle_count: Option<u32>,
+    /// 电量低或电池老化提示
+    pub warnings: Vec<String>,
+}
+
+/// 电池信息汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct BatteryInfo {
+    /// 所有电池（台式机为空）
+    pub batteries: Vec<Battery>,
+    /// 是否有电池电量过低
+    pub low: bool,
+    /// 是否有电池健康度下降
+    pub degraded: bool,
+}
+
+pub struct BatteryMonitor;
+
+impl BatteryMonitor {
+    /// 创建新的电池监控器
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 读取 /sys/class/power_supply 中 type 为 Battery 的设备
+    pub fn get_info(&mut self) -> BatteryInfo {
+        let mut batteries: Vec<Battery> = fs::read_dir("/sys/class/power_supply")
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| read_trimmed(&path.join("type")).as_deref() == Some("Battery"))
+                    // 外设电池 (scope=Device)，如无线鼠标，不计入
+                    .filter(|path| read_trimmed(&path.join("scope")).as_deref() != Some("Device"))
+                    .map(|path| Self::read_battery(&path))
+                    .collect()
+            })
+            .unwrap_or_default();
+        batteries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let low = batteries.iter().any(Self::is_low);
+        let degraded = batteries.iter().any(Self::is_degraded);
+
+        BatteryInfo {
+            batteries,
+            low,
+            degraded,
+        }
+    }
+
+    fn read_battery(path: &Path) -> Battery {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let status = read_trimmed(&path.join("status")).unwrap_or_else(|| "Unknown".to_string());
+        let charging = status == "Charging";
+
+        // 部分电池以 energy_* (µWh) 报告，部分以 charge_* (µAh) 报告，后者需乘以电压换算
+        let voltage = read_number(&path.join("voltage_min_design"))
+            .or_else(|| read_number(&path.join("voltage_now")))
+            .map(|uv| uv / 1_000_000.0);
+        let energy_wh = |energy: &str, charge: &str| {
+            read_number(&path.join(energy))
+                .map(|uwh| uwh / 1_000_000.0)
+                .or_else(|| {
+                    let uah = read_number(&path.join(charge))?;
+                    Some(uah / 1_000_000.0 * voltage?)
+                })
+        };
+        let now_wh = energy_wh("energy_now", "charge_now");
+        let full_wh = energy_wh("energy_full", "charge_full");
+        let design_wh = energy_wh("energy_full_design", "charge_full_design");
+
+        let charge_percent = read_number(&path.join("capacity"))
+            .or_else(|| Some(now_wh? / full_wh.filter(|&full| full > 0.0)? * 100.0))
+            .map(|percent| percent.clamp(0.0, 100.0));
+
+        let health_percent = match (full_wh, design_wh) {
+            (Some(full), Some(design)) if design > 0.0 => Some(full / design * 100.0),
+            _ => None,
+        };
+
+        // power_now (µW) 或 current_now (µA) × voltage_now (µV)，部分固件放电时为负值
+        let discharge_rate_watts = (status == "Discharging")
+            .then(|| {
+                read_number(&path.join("power_now"))
+                    .map(|uw| uw / 1_000_000.0)
+                    .or_else(|| {
+                        let ua = read_number(&path.join("current_now"))?;
+                        let uv = read_number(&path.join("voltage_now"))?;
+                        Some(ua / 1_000_000.0 * uv / 1_000_000.0)
+                    })
+            })
+            .flatten()
+            .map(f32::abs)
+            .filter(|&watts| watts > 0.0);
+
+        let time_remaining_minutes = match (now_wh, discharge_rate_watts) {
+            (Some(now), Some(watts)) => Some((now / watts * 60.0) as u32),
+            _ => None,
+        };
+
+        let mut battery = Battery {
+            name,
+            manufacturer: read_trimmed(&path.join("manufacturer")),
+            model: read_trimmed(&path.join("model_name")),
+            technology: read_trimmed(&path.join("technology")),
+            charge_percent,
+            status,
+            charging,
+            design_capacity_wh: design_wh,
+            full_capacity_wh: full_wh,
+            health_percent,
+            discharge_rate_watts,
+            time_remaining_minutes,
+            cycle_count: read_number(&path.join("cycle_count"))
+                .filter(|&count| count > 0.0)
+                .map(|count| count as u32),
+            warnings: Vec::new(),
+        };
+        battery.warnings = Self::warnings(&battery);
+        battery
+    }
+
+    fn is_low(battery: &Battery) -> bool {
+        !battery.charging
+            && battery
+                .charge_percent
+                .is_some_and(|percent| percent < LOW_CHARGE_PERCENT)
+    }
+
+    fn is_degraded(battery: &Battery) -> bool {
+        battery
+            .health_percent
+            .is_some_and(|health| health < DEGRADED_HEALTH_PERCENT)
+    }
+
+    fn warnings(battery: &Battery) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if Self::is_low(battery) {
+            if let Some(percent) = battery.charge_percent {
+                warnings.push(format!("电量仅剩 {:.0}%，请接通电源", percent));
+            }
+        }
+        if Self::is_degraded(battery) {
+            if let Some(health) = battery.health_percent {
+                warnings.push(format!("电池健康度仅 {:.0}%，续航已明显下降", health));
+            }
+        }
+
+        warnings
+    }
+}
+
+impl Default for BatteryMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn read_number(path: &Path) -> Option<f32> {
+    read_trimmed(path)?.parse().ok()
+}
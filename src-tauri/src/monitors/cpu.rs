@@ -1,4 +1,6 @@
 use serde::Serialize;
+use std::fs;
+use std::time::Instant;
 use sysinfo::{CpuRefreshKind, RefreshKind, System};
 
 /// CPU 信息结构体
@@ -14,10 +16,29 @@ pub struct CpuInfo {
     pub core_usage: Vec<f32>,
     /// CPU 频率 (MHz)
     pub frequency: u64,
+    /// 每秒上下文切换次数
+    pub context_switches_per_sec: f64,
+    /// 每秒中断次数
+    pub interrupts_per_sec: f64,
+    /// 虚拟机中被宿主机占用的 CPU 时间比例 (0-100)，持续偏高说明存在争抢资源的邻居
+    pub steal_percent: f32,
+}
+
+/// /proc/stat 中的累计计数器
+#[derive(Debug, Clone, Copy, Default)]
+struct StatCounters {
+    context_switches: u64,
+    interrupts: u64,
+    /// cpu 汇总行的 steal 时间 (jiffies)
+    steal: u64,
+    /// cpu 汇总行的总时间 (jiffies)
+    total: u64,
 }
 
 pub struct CpuMonitor {
     system: System,
+    /// 上一次采样的计数器，用于计算速率
+    last_counters: Option<(Instant, StatCounters)>,
 }
 
 impl CpuMonitor {
@@ -30,7 +51,10 @@ impl CpuMonitor {
         // 首次刷新 CPU 信息（sysinfo 需要两次刷新才能获取准确的使用率）
         system.refresh_cpu_all();
 
-        Self { system }
+        Self {
+            system,
+            last_counters: None,
+        }
     }
 
     /// 获取 CPU 信息
@@ -38,6 +62,9 @@ impl CpuMonitor {
         // 刷新 CPU 数据
         self.system.refresh_cpu_all();
 
+        // 上下文切换、中断与 steal 时间 (Linux /proc/stat)
+        let (context_switches_per_sec, interrupts_per_sec, steal_percent) = self.sample_rates();
+
         let cpus = self.system.cpus();
 
         // 计算总体使用率（所有核心平均）
@@ -68,8 +95,72 @@ impl CpuMonitor {
             usage: total_usage,
             core_usage,
             frequency,
+            context_switches_per_sec,
+            interrupts_per_sec,
+            steal_percent,
         }
     }
+
+    /// 根据两次采样的 /proc/stat 计数器差值计算速率，首次采样返回 0
+    fn sample_rates(&mut self) -> (f64, f64, f32) {
+        let Some(current) = Self::read_stat() else {
+            return (0.0, 0.0, 0.0);
+        };
+        let now = Instant::now();
+
+        let rates = match self.last_counters {
+            Some((last_time, last)) => {
+                let elapsed = now.duration_since(last_time).as_secs_f64();
+                let total = current.total.saturating_sub(last.total);
+                if elapsed > 0.0 && total > 0 {
+                    let rate = |cur: u64, prev: u64| cur.saturating_sub(prev) as f64 / elapsed;
+                    (
+                        rate(current.context_switches, last.context_switches),
+                        rate(current.interrupts, last.interrupts),
+                        current.steal.saturating_sub(last.steal) as f32 / total as f32 * 100.0,
+                    )
+                } else {
+                    (0.0, 0.0, 0.0)
+                }
+            }
+            None => (0.0, 0.0, 0.0),
+        };
+
+        self.last_counters = Some((now, current));
+        rates
+    }
+
+    /// 读取 /proc/stat 计数器（仅 Linux）
+    ///
+    /// cpu 行格式: user nice system idle iowait irq softirq steal guest guest_nice
+    fn read_stat() -> Option<StatCounters> {
+        let content = fs::read_to_string("/proc/stat").ok()?;
+        let mut counters = StatCounters::default();
+
+        for line in content.lines() {
+            let mut fields = line.split_whitespace();
+            match fields.next() {
+                Some("cpu") => {
+                    // guest 时间已计入 user/nice，不重复累加
+                    let times: Vec<u64> = fields
+                        .take(8)
+                        .map(|value| value.parse().unwrap_or(0))
+                        .collect();
+                    counters.total = times.iter().sum();
+                    counters.steal = times.get(7).copied().unwrap_or(0);
+                }
+                Some("intr") => {
+                    counters.interrupts = fields.next()?.parse().unwrap_or(0);
+                }
+                Some("ctxt") => {
+                    counters.context_switches = fields.next()?.parse().unwrap_or(0);
+                }
+                _ => {}
+            }
+        }
+
+        Some(counters)
+    }
 }
 
 impl Default for CpuMonitor {
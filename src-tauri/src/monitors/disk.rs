@@ -1,5 +1,24 @@
 use serde::Serialize;
-use sysinfo::{Disks, System};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use sysinfo::Disks;
+
+use super::hwmon::list_chips;
+use crate::util::{load_json, read_trimmed, save_json};
+
+/// 排除规则配置文件名
+const EXCLUSIONS_FILE: &str = "disk_exclusions.json";
+/// 默认排除 snap 的 squashfs 挂载、EFI 分区和网络文件系统
+const DEFAULT_EXCLUSIONS: &[&str] = &[
+    "/snap/*",
+    "/boot/efi",
+    "squashfs",
+    "nfs*",
+    "cifs",
+    "smb3",
+    "fuse.sshfs",
+];
 
 /// 单个磁盘信息
 #[derive(Debug, Clone, Serialize)]
@@ -20,6 +39,25 @@ pub struct DiskInfo {
     pub usage_percent: f64,
     /// 是否为可移动磁盘
     pub is_removable: bool,
+    /// 是否以只读方式挂载
+    pub is_read_only: bool,
+    /// 是否在运行期间由读写变为只读（通常意味着磁盘或控制器故障）
+    pub remounted_read_only: bool,
+    /// 是否被排除规则匹配，排除的磁盘不计入汇总
+    pub excluded: bool,
+}
+
+/// 单块物理硬盘的温度
+#[derive(Debug, Clone, Serialize)]
+pub struct DriveTemperature {
+    /// 设备名 (如 nvme1, sda)
+    pub device: String,
+    /// 型号
+    pub model: Option<String>,
+    /// 当前温度 (°C)
+    pub temperature: f32,
+    /// 对应的 hwmon 传感器 ID
+    pub sensor_id: String,
 }
 
 /// 所有磁盘信息汇总
@@ -27,18 +65,28 @@ pub struct DiskInfo {
 pub struct DisksInfo {
     /// 所有磁盘列表
     pub disks: Vec<DiskInfo>,
-    /// 总磁盘数量
+    /// 总磁盘数量（与 disks 一致，含排除的磁盘）
     pub disk_count: usize,
+    /// 计入汇总的磁盘数量（不含排除的磁盘，下同）
+    pub included_count: usize,
     /// 所有磁盘总容量
     pub total_space: u64,
     /// 所有磁盘已用空间
     pub total_used: u64,
     /// 所有磁盘可用空间
     pub total_available: u64,
+    /// 由读写变为只读的文件系统数量
+    pub fs_readonly_count: usize,
+    /// 各物理硬盘温度 (nvme 与 drivetemp 驱动)，按设备名排序
+    pub drive_temperatures: Vec<DriveTemperature>,
 }
 
 pub struct DiskMonitor {
     disks: Disks,
+    /// 曾以读写方式挂载的挂载点，用于区分原本就只读的文件系统
+    writable_mounts: HashSet<String>,
+    /// 排除规则
+    exclusions: Vec<String>,
 }
 
 impl DiskMonitor {
@@ -46,7 +94,11 @@ impl DiskMonitor {
     pub fn new() -> Self {
         let disks = Disks::new_with_refreshed_list();
 
-        Self { disks }
+        Self {
+            disks,
+            writable_mounts: HashSet::new(),
+            exclusions: default_exclusions(),
+        }
     }
 
     /// 获取所有磁盘信息
@@ -68,34 +120,120 @@ impl DiskMonitor {
                 0.0
             };
 
-            total_space += total;
-            total_available += available;
+            let mount_point = disk.mount_point().to_string_lossy().to_string();
+            let file_system = disk.file_system().to_string_lossy().to_string();
+            let excluded = self.is_excluded(&mount_point, &file_system);
+            if !excluded {
+                total_space += total;
+                total_available += available;
+            }
+
+            let is_read_only = disk.is_read_only();
+            if !is_read_only {
+                self.writable_mounts.insert(mount_point.clone());
+            }
+            let remounted_read_only = is_read_only && self.writable_mounts.contains(&mount_point);
 
             let disk_info = DiskInfo {
                 name: disk.name().to_string_lossy().to_string(),
-                mount_point: disk.mount_point().to_string_lossy().to_string(),
-                file_system: String::from_utf8_lossy(disk.file_system()).to_string(),
+                mount_point,
+                file_system,
                 total_space: total,
                 available_space: available,
                 used_space: used,
                 usage_percent,
                 is_removable: disk.is_removable(),
+                is_read_only,
+                remounted_read_only,
+                excluded,
             };
 
             disk_infos.push(disk_info);
         }
 
+        // 已卸载的挂载点不再记录，避免之后同一路径挂载原本只读的文件系统时被误判为重新挂载为只读
+        self.writable_mounts
+            .retain(|mount_point| disk_infos.iter().any(|d| &d.mount_point == mount_point));
+
         let total_used = total_space.saturating_sub(total_available);
+        let included = disk_infos.iter().filter(|d| !d.excluded);
+        let included_count = included.clone().count();
+        let fs_readonly_count = included.filter(|d| d.remounted_read_only).count();
 
         DisksInfo {
             disk_count: disk_infos.len(),
+            included_count,
             disks: disk_infos,
             total_space,
             total_used,
             total_available,
+            fs_readonly_count,
+            drive_temperatures: Self::read_drive_temperatures(),
         }
     }
 
+    /// 设置排除规则
+    pub fn set_exclusions(&mut self, patterns: Vec<String>) {
+        self.exclusions = patterns;
+    }
+
+    /// 当前排除规则
+    pub fn exclusions(&self) -> &[String] {
+        &self.exclusions
+    }
+
+    /// 以 / 或盘符 (如 D:) 开头的规则匹配挂载点，其余匹配文件系统类型
+    fn is_excluded(&self, mount_point: &str, file_system: &str) -> bool {
+        self.exclusions.iter().any(|pattern| {
+            if pattern.starts_with('/') {
+                glob_match(pattern.as_bytes(), mount_point.as_bytes())
+            } else if is_drive_pattern(pattern) {
+                // 盘符不区分大小写，且 "D:" 与 "D:\" 视为同一挂载点
+                let pattern = pattern.trim_end_matches('\\').to_ascii_uppercase();
+                let mount_point = mount_point.trim_end_matches('\\').to_ascii_uppercase();
+                glob_match(pattern.as_bytes(), mount_point.as_bytes())
+            } else {
+                glob_match(pattern.as_bytes(), file_system.as_bytes())
+            }
+        })
+    }
+
+    /// 从 hwmon 读取每块硬盘的温度；SATA 硬盘需要加载 drivetemp 模块
+    fn read_drive_temperatures() -> Vec<DriveTemperature> {
+        let mut drives: Vec<DriveTemperature> = list_chips(Path::new("/sys/class/hwmon"))
+            .into_iter()
+            .filter_map(|(path, chip_id)| {
+                let name = read_trimmed(&path.join("name"))?;
+                let device = fs::canonicalize(path.join("device")).ok()?;
+
+                // nvme 的所属设备即控制器 (nvme0)，drivetemp 的所属设备是 SCSI 设备，块设备名在其 block 目录下
+                let device_name = match name.as_str() {
+                    "nvme" => device.file_name()?.to_string_lossy().to_string(),
+                    "drivetemp" => fs::read_dir(device.join("block"))
+                        .ok()?
+                        .flatten()
+                        .next()?
+                        .file_name()
+                        .to_string_lossy()
+                        .to_string(),
+                    _ => return None,
+                };
+
+                let millidegrees: i64 = read_trimmed(&path.join("temp1_input"))?.parse().ok()?;
+
+                Some(DriveTemperature {
+                    device: device_name,
+                    model: read_trimmed(&device.join("model")),
+                    temperature: millidegrees as f32 / 1000.0,
+                    sensor_id: format!("{}/temp1", chip_id),
+                })
+            })
+            .collect();
+
+        drives.sort_by(|a, b| device_sort_key(&a.device).cmp(&device_sort_key(&b.device)));
+        drives
+    }
+
     /// 格式化磁盘大小为人类可读格式
     pub fn format_bytes(bytes: u64) -> String {
         const KB: u64 = 1024;
@@ -122,3 +260,59 @@ impl Default for DiskMonitor {
         Self::new()
     }
 }
+
+/// 默认排除规则
+pub fn default_exclusions() -> Vec<String> {
+    DEFAULT_EXCLUSIONS
+        .iter()
+        .map(|pattern| pattern.to_string())
+        .collect()
+}
+
+/// 读取排除规则，文件不存在时返回默认规则
+pub fn load_exclusions(dir: &Path) -> Result<Vec<String>, String> {
+    load_json(dir, EXCLUSIONS_FILE, "disk exclusions")
+        .map(|patterns| patterns.unwrap_or_else(default_exclusions))
+}
+
+/// 保存排除规则
+pub fn save_exclusions(dir: &Path, patterns: &[String]) -> Result<(), String> {
+    save_json(dir, EXCLUSIONS_FILE, patterns, "disk exclusions")
+}
+
+/// 设备名排序键：按字母前缀与末尾编号排序，避免字符串顺序下 nvme10 排在 nvme2 之前
+fn device_sort_key(device: &str) -> (&str, Option<u32>) {
+    let prefix = device.trim_end_matches(|c: char| c.is_ascii_digit());
+    (prefix, device[prefix.len()..].parse().ok())
+}
+
+/// 是否为 Windows 盘符开头的规则 (如 D:、E:\Backup\*)
+fn is_drive_pattern(pattern: &str) -> bool {
+    let bytes = pattern.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+/// 通配符匹配：* 匹配任意长度字符，? 匹配单个字符
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match (pattern.first(), text.first()) {
+        (None, None) => true,
+        (Some(b'*'), _) => {
+            glob_match(&pattern[1..], text) || (!text.is_empty() && glob_match(pattern, &text[1..]))
+        }
+        (Some(b'?'), Some(_)) => glob_match(&pattern[1..], &text[1..]),
+        (Some(p), Some(t)) if p == t => glob_match(&pattern[1..], &text[1..]),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_devices_by_index() {
+        let mut devices = vec!["nvme10", "sdb", "nvme2", "sda", "nvme0"];
+        devices.sort_by_key(|device| device_sort_key(device));
+        assert_eq!(devices, ["nvme0", "nvme2", "nvme10", "sda", "sdb"]);
+    }
+}
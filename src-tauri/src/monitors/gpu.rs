@@ -0,0 +1,494 @@
+use serde::Serialize;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::util::{command, read_trimmed};
+
+/// 单个 GPU 信息
+#[derive(Debug, Clone, Serialize)]
+pub struct GpuDevice {
+    /// GPU 名称
+    pub name: String,
+    /// 数据来源后端 (如 tegrastats)
+    pub backend: String,
+    /// GPU 使用率 (0-100)
+    pub utilization: Option<f32>,
+    /// 当前频率 (MHz)
+    pub frequency: Option<u64>,
+    /// 当前功耗 (W)
+    pub power: Option<f32>,
+}
+
+/// 供电轨功耗
+#[derive(Debug, Clone, Serialize)]
+pub struct PowerRail {
+    /// 供电轨名称 (如 VDD_GPU_SOC, POM_5V_IN)
+    pub name: String,
+    /// 当前功耗 (mW)
+    pub current_mw: u32,
+    /// 平均功耗 (mW)
+    pub average_mw: u32,
+}
+
+/// Jetson SoC 专有数据
+#[derive(Debug, Clone, Serialize)]
+pub struct JetsonStats {
+    /// EMC (内存控制器) 带宽占用率 (0-100)
+    pub emc_usage: Option<f32>,
+    /// EMC 频率 (MHz)
+    pub emc_frequency: Option<u64>,
+    /// 已用内存 (字节，CPU/GPU 共享)
+    pub ram_used: Option<u64>,
+    /// 总内存 (字节)
+    pub ram_total: Option<u64>,
+    /// 各供电轨功耗
+    pub rails: Vec<PowerRail>,
+}
+
+/// GPU 信息汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct GpuInfo {
+    /// 检测到的 GPU 列表
+    pub gpus: Vec<GpuDevice>,
+    /// Jetson 设备的 SoC 数据
+    pub jetson: Option<JetsonStats>,
+}
+
+/// Intel 核显 (i915 / xe 驱动) 的 sysfs 数据源
+struct IntelGpu {
+    name: String,
+    /// 驱动名称 (i915 / xe)
+    driver: String,
+    /// 频率文件 (MHz)
+    freq_path: Option<PathBuf>,
+    /// RC6 (空闲休眠) 累计驻留时间文件 (ms)
+    rc6_path: Option<PathBuf>,
+    /// 能耗计数器文件 (µJ)
+    energy_path: Option<PathBuf>,
+    last_rc6: Option<(Instant, u64)>,
+    last_energy: Option<(Instant, u64)>,
+}
+
+/// 等待 tegrastats 输出第一行的最长时间
+const TEGRASTATS_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 旧版 (JetPack 4 Xavier) tegrastats 不带前缀的供电轨名称
+const BARE_RAIL_NAMES: &[&str] = &["GPU", "CPU", "SOC", "CV", "VDDRQ", "SYS5V"];
+/// GPU 供电轨名称，按优先级排列 (Orin / Xavier JP4 / Xavier JP5 / Nano)
+const GPU_RAIL_NAMES: &[&str] = &["VDD_GPU_SOC", "GPU", "VDD_GPU", "POM_5V_GPU"];
+
+pub struct GpuMonitor {
+    is_jetson: bool,
+    intel_gpus: Vec<IntelGpu>,
+    /// 最近一次读取的 tegrastats 输出行
+    tegrastats_line: Option<String>,
+}
+
+impl GpuMonitor {
+    /// 创建新的 GPU 监控器
+    pub fn new() -> Self {
+        // L4T 系统都会提供该文件，NVML 在 Jetson 上不可用
+        let is_jetson = Path::new("/etc/nv_tegra_release").exists();
+
+        Self {
+            is_jetson,
+            intel_gpus: Self::detect_intel_gpus(),
+            tegrastats_line: None,
+        }
+    }
+
+    /// 是否需要读取 tegrastats（仅 Jetson 设备）
+    pub fn needs_tegrastats(&self) -> bool {
+        self.is_jetson
+    }
+
+    /// 启动 tegrastats 并等待一次采样，耗时较长，应在后台线程调用
+    pub fn collect_tegrastats() -> Option<String> {
+        Self::read_tegrastats_line()
+    }
+
+    /// 保存后台线程读取到的 tegrastats 输出行
+    pub fn update_tegrastats(&mut self, line: Option<String>) {
+        self.tegrastats_line = line;
+    }
+
+    /// 获取 GPU 信息
+    pub fn get_info(&mut self) -> GpuInfo {
+        let mut gpus = Vec::new();
+        let mut jetson = None;
+
+        if self.is_jetson {
+            if let Some(line) = &self.tegrastats_line {
+                let (device, stats) = Self::parse_tegrastats(line);
+                gpus.push(device);
+                jetson = Some(stats);
+            }
+        }
+
+        for intel in &mut self.intel_gpus {
+            gpus.push(intel.sample());
+        }
+
+        GpuInfo { gpus, jetson }
+    }
+
+    /// 查找由 i915 或 xe 驱动管理的显卡
+    fn detect_intel_gpus() -> Vec<IntelGpu> {
+        let Ok(entries) = fs::read_dir("/sys/class/drm") else {
+            return Vec::new();
+        };
+
+        let mut cards: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                // 只取 cardN，跳过 card0-eDP-1 之类的显示接口
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_prefix("card"))
+                    .is_some_and(|index| index.chars().all(|c| c.is_ascii_digit()))
+            })
+            .filter(|card| {
+                let driver = fs::read_link(card.join("device/driver")).ok();
+                driver
+                    .as_deref()
+                    .and_then(Path::file_name)
+                    .is_some_and(|driver| driver == "i915" || driver == "xe")
+            })
+            .collect();
+        cards.sort();
+
+        cards.into_iter().map(IntelGpu::new).collect()
+    }
+
+    /// 启动 tegrastats 并读取第一行输出，超时未输出时结束进程并返回 None
+    fn read_tegrastats_line() -> Option<String> {
+        let mut child = command("tegrastats")
+            .args(["--interval", "100"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok()?;
+
+        // 在单独线程中读取，避免 tegrastats 无输出时阻塞后台任务；
+        // 读取线程不等待回收，管道关闭后自行退出
+        let stdout = child.stdout.take()?;
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut line = String::new();
+            let result = BufReader::new(stdout).read_line(&mut line);
+            let _ = tx.send(result.map(|n| (n, line)));
+        });
+        let result = rx.recv_timeout(TEGRASTATS_TIMEOUT);
+
+        // tegrastats 会持续输出，读到一行或超时后立即结束进程
+        let _ = child.kill();
+        let _ = child.wait();
+
+        match result {
+            Ok(Ok((n, line))) if n > 0 => Some(line),
+            _ => None,
+        }
+    }
+
+    /// 解析 tegrastats 输出行
+    ///
+    /// 示例: `RAM 2416/6933MB (lfb 1x4MB) ... EMC_FREQ 3%@2133 GR3D_FREQ 45%@[305] ... VDD_GPU_SOC 1595mW/1595mW`
+    fn parse_tegrastats(line: &str) -> (GpuDevice, JetsonStats) {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        let mut stats = JetsonStats {
+            emc_usage: None,
+            emc_frequency: None,
+            ram_used: None,
+            ram_total: None,
+            rails: Vec::new(),
+        };
+        let mut utilization = None;
+        let mut frequency = None;
+
+        for (i, token) in tokens.iter().enumerate() {
+            let Some(next) = tokens.get(i + 1) else {
+                break;
+            };
+
+            match *token {
+                "RAM" => {
+                    if let Some((used, total)) = next.trim_end_matches("MB").split_once('/') {
+                        stats.ram_used = used.parse::<u64>().ok().map(|mb| mb * 1024 * 1024);
+                        stats.ram_total = total.parse::<u64>().ok().map(|mb| mb * 1024 * 1024);
+                    }
+                }
+                "EMC_FREQ" => {
+                    let (usage, freq) = Self::parse_load_at_freq(next);
+                    stats.emc_usage = usage;
+                    stats.emc_frequency = freq;
+                }
+                "GR3D_FREQ" => {
+                    let (usage, freq) = Self::parse_load_at_freq(next);
+                    utilization = usage;
+                    frequency = freq;
+                }
+                name if Self::is_rail_name(name) => {
+                    if let Some(rail) = Self::parse_rail(name, next) {
+                        stats.rails.push(rail);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // 按名称精确匹配，避免误取 VDD_CPU_GPU_CV 之类的合并供电轨
+        let power = GPU_RAIL_NAMES
+            .iter()
+            .find_map(|name| stats.rails.iter().find(|rail| rail.name == *name))
+            .map(|rail| rail.current_mw as f32 / 1000.0);
+
+        let device = GpuDevice {
+            name: "NVIDIA Tegra GPU".to_string(),
+            backend: "tegrastats".to_string(),
+            utilization,
+            frequency,
+            power,
+        };
+
+        (device, stats)
+    }
+
+    /// 解析 "45%@305"、"45%@[305,305]" 或 "0%" 格式
+    fn parse_load_at_freq(value: &str) -> (Option<f32>, Option<u64>) {
+        let (load, freq) = match value.split_once('@') {
+            Some((load, freq)) => (load, Some(freq)),
+            None => (value, None),
+        };
+
+        let load = load.trim_end_matches('%').parse().ok();
+        let freq = freq.and_then(|f| {
+            f.trim_start_matches('[')
+                .trim_end_matches(']')
+                .split(',')
+                .next()?
+                .parse()
+                .ok()
+        });
+
+        (load, freq)
+    }
+
+    /// 供电轨名称: 新版为 VDD*/VIN_* (含 Orin 的 VDDQ_*)，Nano 为 POM_*，JetPack 4 Xavier 为 GPU、SOC 等不带前缀的名称
+    ///
+    /// CPU 也是 "CPU [2%@1190,...]" 核心负载的标记，其后不是 "a/b" 格式，会在 parse_rail 中被跳过
+    fn is_rail_name(name: &str) -> bool {
+        name.starts_with("VDD")
+            || name.starts_with("VIN_")
+            || name.starts_with("POM_")
+            || BARE_RAIL_NAMES.contains(&name)
+    }
+
+    /// 解析 "1595mW/1595mW" 或旧版 "1595/1595" 格式
+    fn parse_rail(name: &str, value: &str) -> Option<PowerRail> {
+        let (current, average) = value.split_once('/')?;
+
+        Some(PowerRail {
+            name: name.to_string(),
+            current_mw: current.trim_end_matches("mW").parse().ok()?,
+            average_mw: average.trim_end_matches("mW").parse().ok()?,
+        })
+    }
+}
+
+impl Default for GpuMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntelGpu {
+    fn new(card: PathBuf) -> Self {
+        let first_existing = |candidates: &[&str]| {
+            candidates
+                .iter()
+                .map(|candidate| card.join(candidate))
+                .find(|path| path.exists())
+        };
+
+        // i915 旧版接口在 cardN 下，新版在 gt/gt0 下；xe 驱动在 device/tile0/gt0 下
+        let freq_path = first_existing(&[
+            "gt_act_freq_mhz",
+            "gt/gt0/rps_act_freq_mhz",
+            "device/tile0/gt0/freq0/act_freq",
+            "gt_cur_freq_mhz",
+        ]);
+        let rc6_path = first_existing(&[
+            "power/rc6_residency_ms",
+            "gt/gt0/rc6_residency_ms",
+            "device/tile0/gt0/gtidle/idle_residency_ms",
+        ]);
+
+        // 独显通过 hwmon 提供能耗；核显的能耗计入 RAPL 的 uncore 域
+        let energy_path = fs::read_dir(card.join("device/hwmon"))
+            .ok()
+            .and_then(|mut entries| entries.find_map(|entry| entry.ok()))
+            .map(|entry| entry.path().join("energy1_input"))
+            .filter(|path| path.exists())
+            .or_else(Self::rapl_uncore_energy);
+
+        let device_id = read_trimmed(&card.join("device/device"))
+            .map(|id| id.trim_start_matches("0x").to_string())
+            .unwrap_or_default();
+
+        let driver = fs::read_link(card.join("device/driver"))
+            .ok()
+            .and_then(|driver| Some(driver.file_name()?.to_string_lossy().to_string()))
+            .unwrap_or_else(|| "i915".to_string());
+
+        Self {
+            name: format!("Intel Graphics [8086:{}]", device_id),
+            driver,
+            freq_path,
+            rc6_path,
+            energy_path,
+            last_rc6: None,
+            last_energy: None,
+        }
+    }
+
+    /// 查找名称为 uncore 的 RAPL 子域 (intel-rapl:0:N)
+    fn rapl_uncore_energy() -> Option<PathBuf> {
+        fs::read_dir("/sys/class/powercap")
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .find(|path| read_trimmed(&path.join("name")).as_deref() == Some("uncore"))
+            .map(|path| path.join("energy_uj"))
+    }
+
+    /// 采样一次，使用率与功耗根据与上一次采样的差值计算，首次采样为空
+    fn sample(&mut self) -> GpuDevice {
+        let now = Instant::now();
+        let read =
+            |path: &Option<PathBuf>| -> Option<u64> { read_trimmed(path.as_ref()?)?.parse().ok() };
+
+        let frequency = read(&self.freq_path);
+
+        // 不在 RC6 中的时间视为忙碌，与 intel_gpu_top 的 RC6 指标互补
+        let rc6 = read(&self.rc6_path);
+        let utilization = match (rc6, self.last_rc6) {
+            (Some(rc6), Some((last_time, last_rc6))) if rc6 >= last_rc6 => {
+                let elapsed_ms = now.duration_since(last_time).as_secs_f64() * 1000.0;
+                (elapsed_ms > 0.0).then(|| {
+                    let idle = (rc6 - last_rc6) as f64 / elapsed_ms;
+                    ((1.0 - idle).clamp(0.0, 1.0) * 100.0) as f32
+                })
+            }
+            _ => None,
+        };
+        self.last_rc6 = rc6.map(|rc6| (now, rc6));
+
+        // 计数器溢出回绕时跳过本次
+        let energy = read(&self.energy_path);
+        let power = match (energy, self.last_energy) {
+            (Some(energy), Some((last_time, last_energy))) if energy >= last_energy => {
+                let elapsed = now.duration_since(last_time).as_secs_f64();
+                (elapsed > 0.0).then(|| ((energy - last_energy) as f64 / 1e6 / elapsed) as f32)
+            }
+            _ => None,
+        };
+        self.last_energy = energy.map(|energy| (now, energy));
+
+        GpuDevice {
+            name: self.name.clone(),
+            backend: self.driver.clone(),
+            utilization,
+            frequency,
+            power,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Jetson Nano, JetPack 4
+    const NANO_JP4: &str = "RAM 1550/3964MB (lfb 167x4MB) SWAP 0/1982MB (cached 0MB) IRAM 0/252kB(lfb 252kB) CPU [3%@1479,2%@1479,1%@1479,0%@1479] EMC_FREQ 3%@1600 GR3D_FREQ 12%@76 APE 25 PLL@28C CPU@30C PMIC@100C GPU@28C AO@36.5C thermal@29C POM_5V_IN 2180/2180 POM_5V_GPU 81/81 POM_5V_CPU 442/442";
+    /// Xavier NX, JetPack 4
+    const XAVIER_JP4: &str = "RAM 2416/7771MB (lfb 1x4MB) SWAP 0/3885MB (cached 0MB) CPU [2%@1190,1%@1190,0%@1190,0%@1190,off,off] EMC_FREQ 0%@1600 GR3D_FREQ 0%@114 APE 150 MTS fg 0% bg 0% AO@38C GPU@38.5C PMIC@100C AUX@38C CPU@39.5C thermal@38.5C GPU 0/0 CPU 596/596 SOC 1192/1192 CV 0/0 VDDRQ 298/298 SYS5V 2450/2450";
+    /// Xavier NX, JetPack 5
+    const XAVIER_JP5: &str = "RAM 2890/6854MB (lfb 64x4MB) SWAP 0/3427MB (cached 0MB) CPU [5%@1190,3%@1190,off,off,off,off] EMC_FREQ 1%@1600 GR3D_FREQ 20%@[305] VIC_FREQ 115 APE 150 AUX@33C CPU@34.5C thermal@33.9C AO@32.5C GPU@33C PMIC@50C VDD_IN 3834mW/3834mW VDD_CPU_GPU_CV 466mW/466mW VDD_SOC 1165mW/1165mW";
+    /// AGX Orin, JetPack 5
+    const ORIN_JP5: &str = "RAM 4722/30536MB (lfb 6213x4MB) SWAP 0/15268MB (cached 0MB) CPU [1%@729,0%@729,0%@729,0%@729,0%@729,0%@729,0%@729,0%@729] EMC_FREQ 0%@2133 GR3D_FREQ 45%@[305,305] VIC_FREQ 729 APE 174 CV0@-256C CPU@42.937C Tboard@31C SOC2@39.437C Tdiode@34C SOC0@40.062C CV1@-256C GPU@-256C tj@42.937C SOC1@39.593C CV2@-256C VDD_GPU_SOC 2391mW/2391mW VDD_CPU_CV 397mW/397mW VIN_SYS_5V0 3118mW/3118mW VDDQ_VDD2_1V8AO 504mW/504mW";
+
+    fn rail_names(stats: &JetsonStats) -> Vec<&str> {
+        stats.rails.iter().map(|rail| rail.name.as_str()).collect()
+    }
+
+    #[test]
+    fn parses_nano_jp4() {
+        let (gpu, stats) = GpuMonitor::parse_tegrastats(NANO_JP4);
+
+        assert_eq!(gpu.utilization, Some(12.0));
+        assert_eq!(gpu.frequency, Some(76));
+        assert_eq!(gpu.power, Some(0.081));
+        assert_eq!(stats.emc_usage, Some(3.0));
+        assert_eq!(stats.emc_frequency, Some(1600));
+        assert_eq!(stats.ram_used, Some(1550 * 1024 * 1024));
+        assert_eq!(stats.ram_total, Some(3964 * 1024 * 1024));
+        assert_eq!(
+            rail_names(&stats),
+            ["POM_5V_IN", "POM_5V_GPU", "POM_5V_CPU"]
+        );
+    }
+
+    #[test]
+    fn parses_bare_rail_names_on_xavier_jp4() {
+        let (gpu, stats) = GpuMonitor::parse_tegrastats(XAVIER_JP4);
+
+        assert_eq!(gpu.utilization, Some(0.0));
+        assert_eq!(gpu.frequency, Some(114));
+        assert_eq!(gpu.power, Some(0.0));
+        // "CPU [2%@1190,...]" 是核心负载，不应被当作供电轨
+        assert_eq!(
+            rail_names(&stats),
+            ["GPU", "CPU", "SOC", "CV", "VDDRQ", "SYS5V"]
+        );
+        assert_eq!(stats.rails[1].current_mw, 596);
+        assert_eq!(stats.rails[5].average_mw, 2450);
+    }
+
+    #[test]
+    fn ignores_combined_rail_for_gpu_power() {
+        let (gpu, stats) = GpuMonitor::parse_tegrastats(XAVIER_JP5);
+
+        assert_eq!(gpu.utilization, Some(20.0));
+        assert_eq!(gpu.frequency, Some(305));
+        assert_eq!(gpu.power, None);
+        assert_eq!(rail_names(&stats), ["VDD_IN", "VDD_CPU_GPU_CV", "VDD_SOC"]);
+    }
+
+    #[test]
+    fn parses_orin_jp5() {
+        let (gpu, stats) = GpuMonitor::parse_tegrastats(ORIN_JP5);
+
+        assert_eq!(gpu.utilization, Some(45.0));
+        assert_eq!(gpu.frequency, Some(305));
+        assert_eq!(gpu.power, Some(2.391));
+        assert_eq!(stats.emc_frequency, Some(2133));
+        assert_eq!(stats.ram_total, Some(30536 * 1024 * 1024));
+        assert_eq!(
+            rail_names(&stats),
+            [
+                "VDD_GPU_SOC",
+                "VDD_CPU_CV",
+                "VIN_SYS_5V0",
+                "VDDQ_VDD2_1V8AO"
+            ]
+        );
+        assert_eq!(stats.rails[0].current_mw, 2391);
+    }
+}
@@ -0,0 +1,699 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::util::{command, read_millidegrees, read_trimmed};
+
+/// 允许通过 load_sensor_driver 加载的 Super-I/O 驱动
+const SUPERIO_MODULES: &[&str] = &["it87", "nct6775", "nct6683"];
+/// Windows 下通过 LibreHardwareMonitor 的 WMI 接口读取传感器
+const LHM_QUERY: &str = "$ns = 'root/LibreHardwareMonitor'; \
+    @{ hardware = @(Get-CimInstance -Namespace $ns -ClassName Hardware | \
+    Select-Object Identifier, Name); \
+    sensors = @(Get-CimInstance -Namespace $ns -ClassName Sensor | \
+    Select-Object Identifier, Name, SensorType, Parent, Value, Index) } | \
+    ConvertTo-Json -Compress -Depth 3";
+/// 启动 PowerShell 查询耗时较长，LHM 读数缓存一段时间再刷新
+const LHM_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+/// LHM 未运行时，间隔更久再重试
+const LHM_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+/// 带 Super-I/O 芯片的台式机机箱类型 (DMI chassis_type)：
+/// Desktop、Low Profile Desktop、Pizza Box、Mini Tower、Tower、All in One、Space-saving、Mini PC
+const DESKTOP_CHASSIS_TYPES: &[u32] = &[3, 4, 5, 6, 7, 15, 16, 35];
+/// 显卡与硬盘的 hwmon 驱动，其风扇/电压不代表主板传感器可用
+const ADD_IN_CHIPS: &[&str] = &[
+    "amdgpu",
+    "radeon",
+    "nouveau",
+    "i915",
+    "xe",
+    "nvme",
+    "drivetemp",
+];
+/// LibreHardwareMonitor 中显卡与硬盘的硬件标识前缀
+const LHM_ADD_IN_PREFIXES: &[&str] = &["lhm/gpu", "lhm/nvme", "lhm/hdd", "lhm/ssd"];
+
+/// 单个 hwmon 温度通道
+#[derive(Debug, Clone, Serialize)]
+pub struct HwmonTemperature {
+    /// 稳定的传感器 ID (如 coretemp-coretemp.0/temp1)
+    pub id: String,
+    /// 通道名称 (如 temp1)
+    pub channel: String,
+    /// 驱动提供的标签 (如 Package id 0)
+    pub label: Option<String>,
+    /// 当前温度 (°C)
+    pub temperature: f32,
+    /// 临界温度 (°C)，来自 tempN_crit，没有时使用 tempN_max
+    pub critical: Option<f32>,
+}
+
+/// 单个 hwmon 风扇通道
+#[derive(Debug, Clone, Serialize)]
+pub struct HwmonFan {
+    /// 稳定的传感器 ID (如 nct6798-nct6775.656/fan2)
+    pub id: String,
+    /// 通道名称 (如 fan2)
+    pub channel: String,
+    /// 驱动提供的标签 (如 CPU Fan)
+    pub label: Option<String>,
+    /// 当前转速 (RPM)，通道读取失败时为空
+    pub rpm: Option<u32>,
+    /// 同编号 pwm 通道的占空比 (0-100%)
+    pub pwm_percent: Option<f32>,
+}
+
+/// 单个 hwmon 芯片
+#[derive(Debug, Clone, Serialize)]
+pub struct HwmonChip {
+    /// 稳定的芯片 ID，不随 hwmonN 编号变化
+    pub id: String,
+    /// 芯片名称 (如 nct6798, k10temp, coretemp)
+    pub name: String,
+    /// sysfs 路径
+    pub path: String,
+    /// 温度通道数量
+    pub temp_inputs: usize,
+    /// 风扇通道数量
+    pub fan_inputs: usize,
+    /// 电压通道数量
+    pub voltage_inputs: usize,
+    /// 温度通道读数
+    pub temperatures: Vec<HwmonTemperature>,
+    /// 风扇通道读数
+    pub fans: Vec<HwmonFan>,
+}
+
+/// 传感器驱动状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SensorDriverState {
+    /// 已有风扇/电压传感器
+    Available,
+    /// 主板应有 Super-I/O 芯片，但对应驱动未加载
+    DriverMissing,
+    /// 驱动已加载但没有产生传感器（通常是 ACPI 资源冲突）
+    DriverLoadedNoSensors,
+    /// 无法判断（非 Linux、笔记本或未知主板）
+    Unknown,
+}
+
+/// hwmon 传感器信息
+#[derive(Debug, Clone, Serialize)]
+pub struct HwmonInfo {
+    /// 所有 hwmon 芯片
+    pub chips: Vec<HwmonChip>,
+    /// 主板厂商 (DMI)
+    pub board_vendor: Option<String>,
+    /// 主板型号 (DMI)
+    pub board_name: Option<String>,
+    /// 风扇/电压传感器驱动状态
+    pub driver_state: SensorDriverState,
+    /// 建议加载的内核模块
+    pub suggested_module: Option<String>,
+    /// 给用户的处理提示
+    pub hint: Option<String>,
+}
+
+pub struct HwmonMonitor {
+    base_path: PathBuf,
+    /// Windows 下最近一次 LHM 查询的时间与结果，None 表示 LHM 未运行
+    lhm: Option<(Instant, Option<Vec<HwmonChip>>)>,
+}
+
+impl HwmonMonitor {
+    /// 创建新的 hwmon 监控器
+    pub fn new() -> Self {
+        Self {
+            base_path: PathBuf::from("/sys/class/hwmon"),
+            lhm: None,
+        }
+    }
+
+    /// Windows 下 LHM 缓存是否已过期（Linux 直接读取 sysfs，无需刷新）
+    pub fn needs_lhm_refresh(&self) -> bool {
+        cfg!(windows)
+            && self.lhm.as_ref().is_none_or(|(at, chips)| {
+                let interval = if chips.is_some() {
+                    LHM_REFRESH_INTERVAL
+                } else {
+                    LHM_RETRY_INTERVAL
+                };
+                at.elapsed() >= interval
+            })
+    }
+
+    /// 查询 LibreHardwareMonitor（阻塞，会启动 PowerShell）
+    pub fn collect_lhm() -> Option<Vec<HwmonChip>> {
+        Self::read_lhm_chips()
+    }
+
+    /// 用新查询的 LHM 结果更新缓存
+    pub fn update_lhm(&mut self, chips: Option<Vec<HwmonChip>>) {
+        self.lhm = Some((Instant::now(), chips));
+    }
+
+    /// 获取 hwmon 芯片及驱动状态
+    pub fn get_info(&mut self) -> HwmonInfo {
+        let chips = self.read_chips();
+        let board_vendor = read_trimmed(Path::new("/sys/class/dmi/id/board_vendor"));
+        let board_name = read_trimmed(Path::new("/sys/class/dmi/id/board_name"));
+
+        let has_sensors = Self::has_board_sensors(&chips);
+
+        // 笔记本由 EC 管理风扇，同一厂商的主板也没有 Super-I/O 芯片，只对台式机给出建议
+        let suggested_module = board_vendor
+            .as_deref()
+            .filter(|_| Self::is_desktop_chassis())
+            .and_then(Self::module_for_vendor)
+            .map(str::to_string);
+
+        let (driver_state, hint) = Self::driver_state(
+            has_sensors,
+            suggested_module.as_deref(),
+            Self::is_module_loaded,
+        );
+
+        HwmonInfo {
+            chips,
+            board_vendor,
+            board_name,
+            driver_state,
+            suggested_module,
+            hint,
+        }
+    }
+
+    /// 是否有主板传感器芯片提供风扇或电压通道，显卡与硬盘芯片不计入
+    fn has_board_sensors(chips: &[HwmonChip]) -> bool {
+        chips
+            .iter()
+            .filter(|chip| Self::is_board_chip(chip))
+            .any(|chip| chip.fan_inputs > 0 || chip.voltage_inputs > 0)
+    }
+
+    /// 芯片是否属于主板：排除显卡、NVMe 与 drivetemp 等扩展设备的芯片
+    fn is_board_chip(chip: &HwmonChip) -> bool {
+        if ADD_IN_CHIPS.contains(&chip.name.as_str())
+            || LHM_ADD_IN_PREFIXES
+                .iter()
+                .any(|prefix| chip.id.starts_with(prefix))
+        {
+            return false;
+        }
+
+        // 未列出的显卡驱动按所属 PCI 设备的类别判断: 0x03xxxx 显示控制器，0x0108xx NVMe
+        let class = bus_device(&Path::new(&chip.path).join("device"))
+            .and_then(|device| read_trimmed(&device.join("class")));
+        !class.is_some_and(|class| class.starts_with("0x03") || class.starts_with("0x0108"))
+    }
+
+    /// 根据是否有主板传感器与建议的驱动判断驱动状态，并给出提示
+    fn driver_state(
+        has_sensors: bool,
+        suggested_module: Option<&str>,
+        is_module_loaded: impl Fn(&str) -> bool,
+    ) -> (SensorDriverState, Option<String>) {
+        match (suggested_module, has_sensors) {
+            (_, true) => (SensorDriverState::Available, None),
+            (Some(module), false) if is_module_loaded(module) => (
+                SensorDriverState::DriverLoadedNoSensors,
+                Some(format!(
+                    "{} 已加载但没有传感器，可能与 ACPI 资源冲突，可尝试内核参数 acpi_enforce_resources=lax",
+                    module
+                )),
+            ),
+            (Some(module), false) => (
+                SensorDriverState::DriverMissing,
+                Some(format!("检测到主板支持 Super-I/O 传感器，请执行 sudo modprobe {}", module)),
+            ),
+            (None, false) if cfg!(windows) => (
+                SensorDriverState::Unknown,
+                Some("请以管理员身份运行 LibreHardwareMonitor 以读取风扇、电压和主板温度".to_string()),
+            ),
+            (None, false) => (SensorDriverState::Unknown, None),
+        }
+    }
+
+    /// 读取所有温度通道及其芯片名称，供温度排行使用（Windows 下使用缓存的 LHM 结果）
+    pub fn temperature_channels(&self) -> Vec<(String, HwmonTemperature)> {
+        self.read_chips()
+            .into_iter()
+            .flat_map(|chip| {
+                let name = chip.name;
+                chip.temperatures
+                    .into_iter()
+                    .map(move |temp| (name.clone(), temp))
+            })
+            .collect()
+    }
+
+    /// 加载 Super-I/O 驱动（需要 root 权限）
+    pub fn load_driver(module: &str) -> Result<(), String> {
+        if !SUPERIO_MODULES.contains(&module) {
+            return Err(format!("Unsupported sensor driver: {}", module));
+        }
+
+        let output = command("modprobe")
+            .arg(module)
+            .output()
+            .map_err(|e| format!("Failed to run modprobe: {}", e))?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "modprobe {} failed: {}",
+                module,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
+        }
+    }
+
+    /// 读取所有 hwmon 芯片
+    fn read_chips(&self) -> Vec<HwmonChip> {
+        if cfg!(windows) {
+            return self
+                .lhm
+                .as_ref()
+                .and_then(|(_, chips)| chips.clone())
+                .unwrap_or_default();
+        }
+
+        let mut chips: Vec<HwmonChip> = list_chips(&self.base_path)
+            .into_iter()
+            .map(|(path, id)| Self::read_chip(&path, id))
+            .collect();
+
+        chips.sort_by(|a, b| a.id.cmp(&b.id));
+        chips
+    }
+
+    /// 统计单个芯片的各类输入通道并读取温度
+    fn read_chip(path: &Path, chip_id: String) -> HwmonChip {
+        let mut chip = HwmonChip {
+            name: read_trimmed(&path.join("name")).unwrap_or_else(|| "unknown".to_string()),
+            path: path.to_string_lossy().to_string(),
+            temp_inputs: 0,
+            fan_inputs: 0,
+            voltage_inputs: 0,
+            temperatures: Vec::new(),
+            fans: Vec::new(),
+            id: chip_id,
+        };
+
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                let file_name = entry.file_name();
+                let file_name = file_name.to_string_lossy();
+                if !file_name.ends_with("_input") {
+                    continue;
+                }
+
+                if file_name.starts_with("temp") {
+                    chip.temp_inputs += 1;
+                    let channel = file_name.trim_end_matches("_input");
+                    if let Some(temperature) = read_millidegrees(&entry.path()) {
+                        chip.temperatures.push(HwmonTemperature {
+                            id: format!("{}/{}", chip.id, channel),
+                            channel: channel.to_string(),
+                            label: read_trimmed(&path.join(format!("{}_label", channel))),
+                            temperature,
+                            critical: read_millidegrees(&path.join(format!("{}_crit", channel)))
+                                .or_else(|| {
+                                    read_millidegrees(&path.join(format!("{}_max", channel)))
+                                })
+                                .filter(|&critical| critical > 0.0),
+                        });
+                    }
+                } else if file_name.starts_with("fan") {
+                    chip.fan_inputs += 1;
+                    let channel = file_name.trim_end_matches("_input");
+                    chip.fans.push(HwmonFan {
+                        id: format!("{}/{}", chip.id, channel),
+                        channel: channel.to_string(),
+                        label: read_trimmed(&path.join(format!("{}_label", channel))),
+                        // 未接风扇的通道读取可能返回 EIO
+                        rpm: read_trimmed(&entry.path()).and_then(|rpm| rpm.parse().ok()),
+                        pwm_percent: read_pwm_percent(path, channel_index(channel)),
+                    });
+                } else if file_name.starts_with("in") {
+                    chip.voltage_inputs += 1;
+                }
+            }
+        }
+
+        chip.temperatures
+            .sort_by_key(|temp| channel_index(&temp.channel));
+        chip.fans.sort_by_key(|fan| channel_index(&fan.channel));
+        chip
+    }
+
+    /// 将 LibreHardwareMonitor 的硬件与传感器映射为 hwmon 芯片，未运行时返回 None
+    fn read_lhm_chips() -> Option<Vec<HwmonChip>> {
+        let json = run_powershell(LHM_QUERY)?;
+        let hardware = json_array(&json["hardware"]);
+        if hardware.is_empty() {
+            return None;
+        }
+        let sensors = json_array(&json["sensors"]);
+
+        let mut chips: Vec<HwmonChip> = hardware
+            .iter()
+            .filter_map(|hardware| {
+                let identifier = hardware["Identifier"].as_str()?;
+                let chip_id = format!("lhm{}", identifier);
+                let mut chip = HwmonChip {
+                    id: chip_id.clone(),
+                    name: hardware["Name"].as_str().unwrap_or("unknown").to_string(),
+                    path: identifier.to_string(),
+                    temp_inputs: 0,
+                    fan_inputs: 0,
+                    voltage_inputs: 0,
+                    temperatures: Vec::new(),
+                    fans: Vec::new(),
+                };
+
+                let children = sensors
+                    .iter()
+                    .filter(|sensor| sensor["Parent"].as_str() == Some(identifier));
+                for sensor in children {
+                    let sensor_type = sensor["SensorType"].as_str().unwrap_or("");
+                    let label = sensor["Name"].as_str().map(str::to_string);
+                    let value = sensor["Value"].as_f64().map(|value| value as f32);
+                    // 传感器标识形如 /lpc/nct6798d/0/temperature/1
+                    let channel = sensor["Identifier"]
+                        .as_str()
+                        .and_then(|id| id.strip_prefix(identifier))
+                        .unwrap_or("")
+                        .trim_start_matches('/')
+                        .to_string();
+
+                    match sensor_type {
+                        "Temperature" => {
+                            chip.temp_inputs += 1;
+                            if let Some(temperature) = value {
+                                chip.temperatures.push(HwmonTemperature {
+                                    id: format!("{}/{}", chip_id, channel),
+                                    channel,
+                                    label,
+                                    temperature,
+                                    critical: None,
+                                });
+                            }
+                        }
+                        "Fan" => {
+                            chip.fan_inputs += 1;
+                            // 同序号的 Control 传感器为该风扇的 PWM 占空比 (%)
+                            let index = sensor["Index"].as_i64();
+                            let pwm_percent = sensors
+                                .iter()
+                                .find(|control| {
+                                    control["Parent"].as_str() == Some(identifier)
+                                        && control["SensorType"].as_str() == Some("Control")
+                                        && control["Index"].as_i64() == index
+                                })
+                                .and_then(|control| control["Value"].as_f64())
+                                .map(|percent| percent as f32);
+                            chip.fans.push(HwmonFan {
+                                id: format!("{}/{}", chip_id, channel),
+                                channel,
+                                label,
+                                rpm: value.map(|rpm| rpm.max(0.0) as u32),
+                                pwm_percent,
+                            });
+                        }
+                        "Voltage" => chip.voltage_inputs += 1,
+                        _ => {}
+                    }
+                }
+
+                Some(chip)
+            })
+            .collect();
+
+        chips.sort_by(|a, b| a.id.cmp(&b.id));
+        Some(chips)
+    }
+
+    /// 根据主板厂商推断常见的 Super-I/O 驱动
+    fn module_for_vendor(vendor: &str) -> Option<&'static str> {
+        let vendor = vendor.to_lowercase();
+
+        if vendor.contains("gigabyte") {
+            Some("it87")
+        } else if vendor.contains("asus")
+            || vendor.contains("asrock")
+            || vendor.contains("micro-star")
+            || vendor.contains("msi")
+        {
+            Some("nct6775")
+        } else {
+            None
+        }
+    }
+
+    /// 是否为台式机机箱，无法读取 chassis_type 时视为否
+    fn is_desktop_chassis() -> bool {
+        read_trimmed(Path::new("/sys/class/dmi/id/chassis_type"))
+            .and_then(|chassis| chassis.parse().ok())
+            .is_some_and(|chassis: u32| DESKTOP_CHASSIS_TYPES.contains(&chassis))
+    }
+
+    /// 检查内核模块是否已加载
+    fn is_module_loaded(module: &str) -> bool {
+        Path::new("/sys/module").join(module).exists()
+    }
+}
+
+impl Default for HwmonMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 列出 hwmon 芯片目录及其稳定 ID，按 hwmonN 编号排序
+///
+/// ID 为芯片名称加所属的总线设备 (如 coretemp-coretemp.0、nvme-0000:01:00.0)。hwmonN 的编号取决于
+/// 驱动加载顺序，重启后可能变化；没有所属设备的虚拟芯片 (如 acpitz) 使用名称，同名芯片追加序号
+pub fn list_chips(base: &Path) -> Vec<(PathBuf, String)> {
+    let Ok(entries) = fs::read_dir(base) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    paths.sort_by_key(|path| {
+        path.file_name()
+            .map(|name| channel_index(&name.to_string_lossy()))
+    });
+
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = read_trimmed(&path.join("name")).unwrap_or_else(|| "unknown".to_string());
+            let device = bus_device(&path.join("device"))
+                .and_then(|device| Some(device.file_name()?.to_string_lossy().to_string()));
+
+            let id = match device {
+                Some(device) => format!("{}-{}", name, device),
+                None => {
+                    let count = seen.entry(name.clone()).or_insert(0);
+                    let id = match *count {
+                        0 => name,
+                        n => format!("{}#{}", name, n),
+                    };
+                    *count += 1;
+                    id
+                }
+            };
+            (path, id)
+        })
+        .collect()
+}
+
+/// 解析 hwmon 芯片所属的总线设备 (PCI、platform 等)
+///
+/// nvme 等驱动把 hwmon 挂在类设备 (nvme0) 下，类设备的编号同样取决于探测顺序，
+/// 因此沿 device 链接向上找到真正的总线设备 (如 0000:01:00.0)
+fn bus_device(device: &Path) -> Option<PathBuf> {
+    let mut device = fs::canonicalize(device).ok()?;
+
+    // 类设备的 subsystem 指向 /sys/class/*，总线设备指向 /sys/bus/*
+    for _ in 0..4 {
+        let is_class_device = fs::canonicalize(device.join("subsystem"))
+            .ok()
+            .and_then(|subsystem| Some(subsystem.parent()?.file_name()? == "class"))
+            .unwrap_or(false);
+        if !is_class_device {
+            break;
+        }
+        match fs::canonicalize(device.join("device")) {
+            Ok(parent) => device = parent,
+            Err(_) => break,
+        }
+    }
+
+    Some(device)
+}
+
+/// 通道编号 (temp10 -> 10)，用于按数字顺序排序
+fn channel_index(channel: &str) -> u32 {
+    channel
+        .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+        .parse()
+        .unwrap_or(0)
+}
+
+/// 读取 pwmN (0-255) 并换算为百分比；大多数 Super-I/O 驱动中 pwmN 控制 fanN
+fn read_pwm_percent(chip: &Path, index: u32) -> Option<f32> {
+    let pwm: u32 = read_trimmed(&chip.join(format!("pwm{}", index)))?
+        .parse()
+        .ok()?;
+    Some(pwm.min(255) as f32 / 255.0 * 100.0)
+}
+
+/// 运行 PowerShell 脚本并解析 JSON 输出
+fn run_powershell(script: &str) -> Option<Value> {
+    let output = command("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", script])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    serde_json::from_slice(&output.stdout).ok()
+}
+
+/// ConvertTo-Json 会把单元素数组输出为对象
+fn json_array(value: &Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items.clone(),
+        Value::Null => Vec::new(),
+        item => vec![item.clone()],
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+
+    /// 在临时目录中构建与 /sys 相同结构的 hwmon 与设备目录
+    struct FakeSysfs(PathBuf);
+
+    impl FakeSysfs {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!(
+                "skywidget-hwmon-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&root);
+            for dir in ["class/hwmon", "class/nvme", "bus/pci", "bus/platform"] {
+                fs::create_dir_all(root.join(dir)).unwrap();
+            }
+            Self(root)
+        }
+
+        /// 创建设备目录，subsystem 指向 class/* 或 bus/*
+        fn device(&self, path: &str, subsystem: &str) -> PathBuf {
+            let device = self.0.join("devices").join(path);
+            fs::create_dir_all(&device).unwrap();
+            symlink(self.0.join(subsystem), device.join("subsystem")).unwrap();
+            device
+        }
+
+        fn chip(&self, hwmon: &str, name: &str, device: Option<&Path>) -> PathBuf {
+            let chip = self.0.join("class/hwmon").join(hwmon);
+            fs::create_dir_all(&chip).unwrap();
+            fs::write(chip.join("name"), format!("{}\n", name)).unwrap();
+            if let Some(device) = device {
+                symlink(device, chip.join("device")).unwrap();
+            }
+            chip
+        }
+
+        fn read_chips(&self) -> Vec<HwmonChip> {
+            HwmonMonitor {
+                base_path: self.0.join("class/hwmon"),
+                lhm: None,
+            }
+            .read_chips()
+        }
+
+        fn ids(&self) -> Vec<String> {
+            list_chips(&self.0.join("class/hwmon"))
+                .into_iter()
+                .map(|(_, id)| id)
+                .collect()
+        }
+    }
+
+    impl Drop for FakeSysfs {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn nvme_chips_use_pci_address() {
+        let sysfs = FakeSysfs::new("nvme");
+        let pci = sysfs.device("pci0000:00/0000:01:00.0", "bus/pci");
+        let nvme = sysfs.device("pci0000:00/0000:01:00.0/nvme/nvme1", "class/nvme");
+        symlink(&pci, nvme.join("device")).unwrap();
+        let coretemp = sysfs.device("platform/coretemp.0", "bus/platform");
+
+        sysfs.chip("hwmon2", "nvme", Some(&nvme));
+        sysfs.chip("hwmon1", "coretemp", Some(&coretemp));
+
+        assert_eq!(sysfs.ids(), ["coretemp-coretemp.0", "nvme-0000:01:00.0"]);
+    }
+
+    #[test]
+    fn deviceless_chips_are_numbered_by_name() {
+        let sysfs = FakeSysfs::new("virtual");
+        sysfs.chip("hwmon10", "acpitz", None);
+        sysfs.chip("hwmon2", "acpitz", None);
+        sysfs.chip("hwmon0", "acpitz", None);
+        sysfs.chip("hwmon1", "pch_cannonlake", None);
+
+        assert_eq!(
+            sysfs.ids(),
+            ["acpitz", "pch_cannonlake", "acpitz#1", "acpitz#2"]
+        );
+    }
+
+    #[test]
+    fn gpu_fans_do_not_count_as_board_sensors() {
+        let sysfs = FakeSysfs::new("gpu-fan");
+        // 独立显卡驱动未列在 ADD_IN_CHIPS 中时，按 PCI 类别识别
+        let gpu = sysfs.device("pci0000:00/0000:03:00.0", "bus/pci");
+        fs::write(gpu.join("class"), "0x030000\n").unwrap();
+        let amdgpu = sysfs.chip("hwmon3", "amdgpu", Some(&gpu));
+        fs::write(amdgpu.join("fan1_input"), "1200\n").unwrap();
+        fs::write(amdgpu.join("in0_input"), "800\n").unwrap();
+        let other_gpu = sysfs.chip("hwmon4", "vendor_gpu", Some(&gpu));
+        fs::write(other_gpu.join("fan1_input"), "900\n").unwrap();
+        let coretemp = sysfs.device("platform/coretemp.0", "bus/platform");
+        let cpu = sysfs.chip("hwmon1", "coretemp", Some(&coretemp));
+        fs::write(cpu.join("temp1_input"), "45000\n").unwrap();
+
+        let chips = sysfs.read_chips();
+        assert_eq!(chips.iter().filter(|chip| chip.fan_inputs > 0).count(), 2);
+        assert!(!HwmonMonitor::has_board_sensors(&chips));
+
+        let (state, hint) = HwmonMonitor::driver_state(false, Some("nct6775"), |_| false);
+        assert_eq!(state, SensorDriverState::DriverMissing);
+        assert!(hint.is_some_and(|hint| hint.contains("modprobe nct6775")));
+
+        // 加载 Super-I/O 驱动后出现主板风扇
+        let superio = sysfs.device("platform/nct6775.656", "bus/platform");
+        let board = sysfs.chip("hwmon5", "nct6798", Some(&superio));
+        fs::write(board.join("fan2_input"), "650\n").unwrap();
+        assert!(HwmonMonitor::has_board_sensors(&sysfs.read_chips()));
+    }
+}
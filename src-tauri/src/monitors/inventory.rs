@@ -0,0 +1,224 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::path::Path;
+
+use super::hwmon::list_chips;
+use crate::util::read_trimmed;
+
+/// 保留的硬件变化事件数量
+const MAX_CHANGES: usize = 100;
+
+/// 单个硬件条目
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryItem {
+    /// 类别: disk, dimm, gpu, sensor
+    pub kind: String,
+    /// 类别内唯一的标识 (设备名、插槽或 PCI 地址)
+    pub id: String,
+    /// 描述 (型号、容量等)
+    pub description: String,
+}
+
+/// 硬件变化事件
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryChange {
+    /// 发生时间 (毫秒时间戳)
+    pub timestamp: i64,
+    /// true 为新增，false 为消失
+    pub added: bool,
+    pub item: InventoryItem,
+}
+
+/// 硬件清单
+#[derive(Debug, Clone, Serialize)]
+pub struct InventoryInfo {
+    /// 当前硬件列表，按类别和标识排序
+    pub items: Vec<InventoryItem>,
+    /// 本次运行期间检测到的变化，按时间升序
+    pub changes: Vec<InventoryChange>,
+}
+
+pub struct InventoryMonitor {
+    last: Option<BTreeMap<(String, String), InventoryItem>>,
+    changes: VecDeque<InventoryChange>,
+}
+
+impl InventoryMonitor {
+    /// 创建新的硬件清单监控器
+    pub fn new() -> Self {
+        Self {
+            last: None,
+            changes: VecDeque::new(),
+        }
+    }
+
+    /// 采集当前清单，并记录与上一次采样相比新增或消失的硬件
+    pub fn get_info(&mut self) -> InventoryInfo {
+        let current: BTreeMap<(String, String), InventoryItem> = Self::read_disks()
+            .into_iter()
+            .chain(Self::read_dimms())
+            .chain(Self::read_gpus())
+            .chain(Self::read_sensors())
+            .map(|item| ((item.kind.clone(), item.id.clone()), item))
+            .collect();
+
+        if let Some(last) = &self.last {
+            let timestamp = chrono::Utc::now().timestamp_millis();
+            let removed = last
+                .iter()
+                .filter(|(key, _)| !current.contains_key(*key))
+                .map(|(_, item)| (false, item));
+            let added = current
+                .iter()
+                .filter(|(key, _)| !last.contains_key(*key))
+                .map(|(_, item)| (true, item));
+
+            for (added, item) in removed.chain(added) {
+                if self.changes.len() == MAX_CHANGES {
+                    self.changes.pop_front();
+                }
+                self.changes.push_back(InventoryChange {
+                    timestamp,
+                    added,
+                    item: item.clone(),
+                });
+            }
+        }
+
+        let items = current.values().cloned().collect();
+        self.last = Some(current);
+
+        InventoryInfo {
+            items,
+            changes: self.changes.iter().cloned().collect(),
+        }
+    }
+
+    /// 块设备，忽略 loop、ram、zram 与 device-mapper 等虚拟设备
+    fn read_disks() -> Vec<InventoryItem> {
+        const VIRTUAL_PREFIXES: [&str; 5] = ["loop", "ram", "zram", "dm-", "md"];
+
+        read_dir_paths("/sys/block")
+            .into_iter()
+            .filter(|path| path.join("device").exists())
+            .filter_map(|path| {
+                let name = path.file_name()?.to_string_lossy().to_string();
+                if VIRTUAL_PREFIXES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+                {
+                    return None;
+                }
+
+                let model = read_trimmed(&path.join("device/model"));
+                let serial = read_trimmed(&path.join("device/serial"));
+                // size 以 512 字节扇区为单位
+                let size_gb = read_trimmed(&path.join("size"))
+                    .and_then(|sectors| sectors.parse::<u64>().ok())
+                    .map(|sectors| sectors * 512 / 1_000_000_000);
+
+                let mut parts: Vec<String> = model.into_iter().collect();
+                if let Some(size_gb) = size_gb {
+                    parts.push(format!("{} GB", size_gb));
+                }
+                if let Some(serial) = serial {
+                    parts.push(format!("S/N {}", serial));
+                }
+
+                Some(InventoryItem {
+                    kind: "disk".to_string(),
+                    id: name,
+                    description: parts.join(", "),
+                })
+            })
+            .collect()
+    }
+
+    /// 内存条，来自 EDAC（未加载 EDAC 驱动时为空）
+    fn read_dimms() -> Vec<InventoryItem> {
+        read_dir_paths("/sys/devices/system/edac/mc")
+            .into_iter()
+            .flat_map(|mc| read_dir_paths(&mc))
+            .filter(|path| {
+                path.file_name().is_some_and(|name| {
+                    let name = name.to_string_lossy();
+                    name.starts_with("dimm") || name.starts_with("rank")
+                })
+            })
+            .filter_map(|path| {
+                let size_mb = read_trimmed(&path.join("size"))?.parse::<u64>().ok()?;
+                if size_mb == 0 {
+                    return None;
+                }
+                let id = read_trimmed(&path.join("dimm_label"))
+                    .or_else(|| read_trimmed(&path.join("dimm_location")))?;
+                let mem_type = read_trimmed(&path.join("dimm_mem_type"));
+
+                Some(InventoryItem {
+                    kind: "dimm".to_string(),
+                    id,
+                    description: match mem_type {
+                        Some(mem_type) => format!("{} MB {}", size_mb, mem_type),
+                        None => format!("{} MB", size_mb),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// 显卡，来自 /sys/class/drm 的 cardN，以 PCI 地址为标识
+    fn read_gpus() -> Vec<InventoryItem> {
+        read_dir_paths("/sys/class/drm")
+            .into_iter()
+            .filter(|path| {
+                path.file_name().is_some_and(|name| {
+                    let name = name.to_string_lossy();
+                    name.starts_with("card") && !name.contains('-')
+                })
+            })
+            .filter_map(|path| {
+                let device = fs::canonicalize(path.join("device")).ok()?;
+                let id = device.file_name()?.to_string_lossy().to_string();
+                let vendor = read_trimmed(&device.join("vendor")).unwrap_or_default();
+                let product = read_trimmed(&device.join("device")).unwrap_or_default();
+                let driver = fs::canonicalize(device.join("driver"))
+                    .ok()
+                    .and_then(|driver| Some(driver.file_name()?.to_string_lossy().to_string()));
+
+                Some(InventoryItem {
+                    kind: "gpu".to_string(),
+                    id,
+                    description: match driver {
+                        Some(driver) => format!("{}:{} ({})", vendor, product, driver),
+                        None => format!("{}:{}", vendor, product),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// 传感器芯片，使用与 hwmon 监控相同的稳定标识
+    fn read_sensors() -> Vec<InventoryItem> {
+        list_chips(Path::new("/sys/class/hwmon"))
+            .into_iter()
+            .map(|(path, id)| InventoryItem {
+                kind: "sensor".to_string(),
+                id,
+                description: read_trimmed(&path.join("name")).unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+impl Default for InventoryMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn read_dir_paths(dir: impl AsRef<Path>) -> Vec<std::path::PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default()
+}
@@ -1,6 +1,12 @@
 use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
 use sysinfo::System;
 
+use crate::util::read_trimmed;
+
 /// 内存信息结构体
 #[derive(Debug, Clone, Serialize)]
 pub struct MemoryInfo {
@@ -18,10 +24,57 @@ pub struct MemoryInfo {
     pub swap_used: u64,
     /// 交换分区使用率 (0-100)
     pub swap_usage_percent: f64,
+    /// 页面缓存 (字节)
+    pub cached: u64,
+    /// 块设备缓冲 (字节)
+    pub buffers: u64,
+    /// 等待写回的脏页 (字节)
+    pub dirty: u64,
+    /// 正在写回的页 (字节)
+    pub writeback: u64,
+    /// 缺页率 (次/秒)
+    pub page_faults_per_sec: f64,
+    /// 主缺页率 (次/秒，需要读盘)
+    pub major_faults_per_sec: f64,
+    /// 换入速率 (页/秒)
+    pub swap_in_per_sec: f64,
+    /// 换出速率 (页/秒)
+    pub swap_out_per_sec: f64,
+    /// ECC 内存错误（仅在 EDAC 可用时提供）
+    pub ecc: Option<EccErrors>,
+}
+
+/// ECC 内存错误统计 (Linux EDAC)
+#[derive(Debug, Clone, Serialize)]
+pub struct EccErrors {
+    /// 累计可纠正错误数
+    pub correctable: u64,
+    /// 累计不可纠正错误数
+    pub uncorrectable: u64,
+    /// 最近一小时内新增的可纠正错误数，采样历史不足一小时时为空
+    pub correctable_last_hour: Option<u64>,
+    /// 最近一小时内新增的不可纠正错误数，采样历史不足一小时时为空
+    pub uncorrectable_last_hour: Option<u64>,
+}
+
+/// ECC 错误速率的统计窗口
+const ECC_WINDOW: Duration = Duration::from_secs(3600);
+
+/// /proc/vmstat 中的累计计数器
+#[derive(Debug, Clone, Copy, Default)]
+struct VmCounters {
+    page_faults: u64,
+    major_faults: u64,
+    swap_in: u64,
+    swap_out: u64,
 }
 
 pub struct MemoryMonitor {
     system: System,
+    /// 上一次采样的计数器，用于计算速率
+    last_counters: Option<(Instant, VmCounters)>,
+    /// 窗口内的 ECC 计数采样 (时间, 可纠正, 不可纠正)
+    ecc_samples: VecDeque<(Instant, u64, u64)>,
 }
 
 impl MemoryMonitor {
@@ -30,7 +83,11 @@ impl MemoryMonitor {
         let mut system = System::new_all();
         system.refresh_memory();
 
-        Self { system }
+        Self {
+            system,
+            last_counters: None,
+            ecc_samples: VecDeque::new(),
+        }
     }
 
     /// 获取内存信息
@@ -55,6 +112,12 @@ impl MemoryMonitor {
             0.0
         };
 
+        let meminfo = Self::read_meminfo();
+        let meminfo_bytes = |key: &str| meminfo.get(key).copied().unwrap_or(0) * 1024;
+
+        let (page_faults_per_sec, major_faults_per_sec, swap_in_per_sec, swap_out_per_sec) =
+            self.sample_rates();
+
         MemoryInfo {
             total,
             used,
@@ -63,9 +126,138 @@ impl MemoryMonitor {
             swap_total,
             swap_used,
             swap_usage_percent,
+            cached: meminfo_bytes("Cached"),
+            buffers: meminfo_bytes("Buffers"),
+            dirty: meminfo_bytes("Dirty"),
+            writeback: meminfo_bytes("Writeback"),
+            page_faults_per_sec,
+            major_faults_per_sec,
+            swap_in_per_sec,
+            swap_out_per_sec,
+            ecc: self.sample_ecc(),
         }
     }
 
+    /// 读取 EDAC 计数并计算最近一小时的增量
+    fn sample_ecc(&mut self) -> Option<EccErrors> {
+        let (correctable, uncorrectable) = Self::read_edac_counts()?;
+        let now = Instant::now();
+
+        // 保留窗口起点之前最新的一个采样作为基线，仅当第二个采样也已超出窗口时才丢弃队首，
+        // 这样轮询间隔超过一小时时仍有基线可用
+        self.ecc_samples.push_back((now, correctable, uncorrectable));
+        while let Some(&(time, _, _)) = self.ecc_samples.get(1) {
+            if now.duration_since(time) >= ECC_WINDOW {
+                self.ecc_samples.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        // 历史不足一个完整窗口时无法给出一小时增量
+        let baseline = self
+            .ecc_samples
+            .front()
+            .filter(|&&(time, _, _)| now.duration_since(time) >= ECC_WINDOW)
+            .copied();
+
+        Some(EccErrors {
+            correctable,
+            uncorrectable,
+            correctable_last_hour: baseline.map(|(_, ce, _)| correctable.saturating_sub(ce)),
+            uncorrectable_last_hour: baseline.map(|(_, _, ue)| uncorrectable.saturating_sub(ue)),
+        })
+    }
+
+    /// 汇总所有内存控制器的 EDAC 计数（仅 Linux）
+    fn read_edac_counts() -> Option<(u64, u64)> {
+        let entries = fs::read_dir("/sys/devices/system/edac/mc").ok()?;
+        let read_count =
+            |path: &Path| -> u64 { read_trimmed(path).and_then(|s| s.parse().ok()).unwrap_or(0) };
+
+        let mut found = false;
+        let mut correctable = 0;
+        let mut uncorrectable = 0;
+
+        for entry in entries.flatten() {
+            if !entry.file_name().to_string_lossy().starts_with("mc") {
+                continue;
+            }
+            found = true;
+            correctable += read_count(&entry.path().join("ce_count"));
+            uncorrectable += read_count(&entry.path().join("ue_count"));
+        }
+
+        found.then_some((correctable, uncorrectable))
+    }
+
+    /// 根据两次采样的 vmstat 计数器差值计算速率，首次采样返回 0
+    fn sample_rates(&mut self) -> (f64, f64, f64, f64) {
+        let Some(current) = Self::read_vmstat() else {
+            return (0.0, 0.0, 0.0, 0.0);
+        };
+        let now = Instant::now();
+
+        let rates = match self.last_counters {
+            Some((last_time, last)) => {
+                let elapsed = now.duration_since(last_time).as_secs_f64();
+                if elapsed > 0.0 {
+                    let rate = |cur: u64, prev: u64| cur.saturating_sub(prev) as f64 / elapsed;
+                    (
+                        rate(current.page_faults, last.page_faults),
+                        rate(current.major_faults, last.major_faults),
+                        rate(current.swap_in, last.swap_in),
+                        rate(current.swap_out, last.swap_out),
+                    )
+                } else {
+                    (0.0, 0.0, 0.0, 0.0)
+                }
+            }
+            None => (0.0, 0.0, 0.0, 0.0),
+        };
+
+        self.last_counters = Some((now, current));
+        rates
+    }
+
+    /// 读取 /proc/vmstat 计数器（仅 Linux）
+    fn read_vmstat() -> Option<VmCounters> {
+        let content = fs::read_to_string("/proc/vmstat").ok()?;
+        let mut counters = VmCounters::default();
+
+        for line in content.lines() {
+            let Some((key, value)) = line.split_once(' ') else {
+                continue;
+            };
+            let value = value.trim().parse().unwrap_or(0);
+            match key {
+                "pgfault" => counters.page_faults = value,
+                "pgmajfault" => counters.major_faults = value,
+                "pswpin" => counters.swap_in = value,
+                "pswpout" => counters.swap_out = value,
+                _ => {}
+            }
+        }
+
+        Some(counters)
+    }
+
+    /// 读取 /proc/meminfo，数值单位为 KB（仅 Linux）
+    fn read_meminfo() -> HashMap<String, u64> {
+        let Ok(content) = fs::read_to_string("/proc/meminfo") else {
+            return HashMap::new();
+        };
+
+        content
+            .lines()
+            .filter_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                let value = value.split_whitespace().next()?.parse().ok()?;
+                Some((key.to_string(), value))
+            })
+            .collect()
+    }
+
     /// 格式化内存大小为人类可读格式
     pub fn format_bytes(bytes: u64) -> String {
         const KB: u64 = 1024;
@@ -2,8 +2,30 @@
 pub mod cpu;
 pub mod memory;
 pub mod disk;
+pub mod pressure;
+pub mod thermal;
+pub mod gpu;
+pub mod hwmon;
+pub mod vm;
+pub mod network;
+pub mod process;
+pub mod smart;
+pub mod power_state;
+pub mod battery;
+pub mod inventory;
 
 // 重新导出便于使用
 pub use cpu::CpuMonitor;
 pub use memory::MemoryMonitor;
 pub use disk::DiskMonitor;
+pub use pressure::PressureMonitor;
+pub use thermal::ThermalMonitor;
+pub use gpu::GpuMonitor;
+pub use hwmon::HwmonMonitor;
+pub use vm::VmMonitor;
+pub use network::NetworkMonitor;
+pub use process::ProcessMonitor;
+pub use smart::SmartMonitor;
+pub use power_state::PowerStateMonitor;
+pub use battery::BatteryMonitor;
+pub use inventory::InventoryMonitor;
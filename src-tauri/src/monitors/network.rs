@@ -0,0 +1,197 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::util::{load_json, read_trimmed, save_json};
+
+/// 期望速率配置文件名
+const EXPECTED_SPEEDS_FILE: &str = "network_expected_speeds.json";
+
+/// 单个网卡链路信息
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkInterface {
+    /// 接口名称
+    pub name: String,
+    /// 内核报告的运行状态 (up, down, dormant, unknown ...)
+    pub operstate: String,
+    /// 物理链路是否连通
+    pub link_up: bool,
+    /// 协商速率 (Mbps)，链路断开或不支持时为空
+    pub speed: Option<u64>,
+    /// 双工模式 (full / half)
+    pub duplex: Option<String>,
+    /// 是否为物理网卡（存在 device 链接）
+    pub is_physical: bool,
+    /// 配置的期望速率 (Mbps)
+    pub expected_speed: Option<u64>,
+    /// 协商速率是否低于期望速率
+    pub below_expected_speed: bool,
+    /// 运行期间链路由连通变为断开的次数
+    pub link_drop_count: u32,
+    /// 最近一次链路断开的时间 (毫秒时间戳)
+    pub last_link_drop: Option<i64>,
+}
+
+/// 网络链路信息汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkInfo {
+    /// 所有接口
+    pub interfaces: Vec<NetworkInterface>,
+    /// 链路断开的物理网卡数量（仅统计曾经连通或配置了期望速率的网卡，忽略从未接线的网口）
+    pub link_down_count: usize,
+    /// 速率低于期望值的网卡数量
+    pub degraded_count: usize,
+}
+
+pub struct NetworkMonitor {
+    base_path: PathBuf,
+    /// 各接口的期望速率 (Mbps)
+    expected_speeds: HashMap<String, u64>,
+    /// 各接口的链路历史
+    link_history: HashMap<String, LinkHistory>,
+}
+
+/// 单个接口在运行期间的链路历史
+#[derive(Default)]
+struct LinkHistory {
+    /// 上一次采样是否连通
+    link_up: bool,
+    /// 是否曾经连通
+    ever_up: bool,
+    /// 断开次数
+    drop_count: u32,
+    /// 最近一次断开的时间 (毫秒时间戳)
+    last_drop: Option<i64>,
+}
+
+impl NetworkMonitor {
+    /// 创建新的网络链路监控器
+    pub fn new() -> Self {
+        Self {
+            base_path: PathBuf::from("/sys/class/net"),
+            expected_speeds: HashMap::new(),
+            link_history: HashMap::new(),
+        }
+    }
+
+    /// 获取网络链路信息
+    pub fn get_info(&mut self) -> NetworkInfo {
+        let mut names: Vec<String> = fs::read_dir(&self.base_path)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.file_name().to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+
+        let interfaces: Vec<NetworkInterface> = names
+            .into_iter()
+            .map(|name| self.read_interface(name))
+            .collect();
+
+        let link_down_count = interfaces
+            .iter()
+            .filter(|iface| iface.is_physical && !iface.link_up)
+            .filter(|iface| {
+                iface.expected_speed.is_some()
+                    || self
+                        .link_history
+                        .get(&iface.name)
+                        .is_some_and(|history| history.ever_up)
+            })
+            .count();
+
+        NetworkInfo {
+            link_down_count,
+            degraded_count: interfaces
+                .iter()
+                .filter(|iface| iface.below_expected_speed)
+                .count(),
+            interfaces,
+        }
+    }
+
+    /// 设置各接口的期望速率
+    pub fn set_expected_speeds(&mut self, speeds: HashMap<String, u64>) {
+        self.expected_speeds = speeds;
+    }
+
+    /// 设置或清除单个接口的期望速率
+    pub fn set_expected_speed(&mut self, interface: &str, speed: Option<u64>) {
+        match speed {
+            Some(speed) => self.expected_speeds.insert(interface.to_string(), speed),
+            None => self.expected_speeds.remove(interface),
+        };
+    }
+
+    /// 当前的期望速率配置
+    pub fn expected_speeds(&self) -> &HashMap<String, u64> {
+        &self.expected_speeds
+    }
+
+    /// 读取单个接口
+    fn read_interface(&mut self, name: String) -> NetworkInterface {
+        let path = self.base_path.join(&name);
+        let read = |file: &str| read_trimmed(&path.join(file));
+
+        let operstate = read("operstate").unwrap_or_else(|| "unknown".to_string());
+        // 接口被管理性关闭时读取 carrier 会返回 EINVAL
+        let link_up = match read("carrier") {
+            Some(carrier) => carrier == "1",
+            None => operstate == "up",
+        };
+
+        // 链路断开时 speed 为 -1 或无法读取
+        let speed = read("speed")
+            .and_then(|s| s.parse::<i64>().ok())
+            .filter(|&speed| speed > 0)
+            .map(|speed| speed as u64);
+        let duplex = read("duplex").filter(|duplex| duplex != "unknown");
+
+        let expected_speed = self.expected_speeds.get(&name).copied();
+        let below_expected_speed = match (expected_speed, speed) {
+            (Some(expected), Some(speed)) => link_up && speed < expected,
+            _ => false,
+        };
+
+        let history = self.link_history.entry(name.clone()).or_default();
+        if history.link_up && !link_up {
+            history.drop_count += 1;
+            history.last_drop = Some(chrono::Utc::now().timestamp_millis());
+        }
+        history.link_up = link_up;
+        history.ever_up |= link_up;
+
+        NetworkInterface {
+            operstate,
+            link_up,
+            speed,
+            duplex,
+            is_physical: path.join("device").exists(),
+            expected_speed,
+            below_expected_speed,
+            link_drop_count: history.drop_count,
+            last_link_drop: history.last_drop,
+            name,
+        }
+    }
+}
+
+impl Default for NetworkMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 读取期望速率配置，文件不存在时返回空
+pub fn load_expected_speeds(dir: &Path) -> Result<HashMap<String, u64>, String> {
+    load_json(dir, EXPECTED_SPEEDS_FILE, "expected link speeds").map(Option::unwrap_or_default)
+}
+
+/// 保存期望速率配置
+pub fn save_expected_speeds(dir: &Path, speeds: &HashMap<String, u64>) -> Result<(), String> {
+    save_json(dir, EXPECTED_SPEEDS_FILE, speeds, "expected link speeds")
+}
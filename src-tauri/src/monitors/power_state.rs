@@ -0,0 +1,160 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs;
+
+use crate::util::read_trimmed;
+
+/// 保留的状态变化事件数量
+const MAX_EVENTS: usize = 100;
+
+/// 状态变化事件
+#[derive(Debug, Clone, Serialize)]
+pub struct PowerStateEvent {
+    /// 发生时间 (毫秒时间戳)
+    pub timestamp: i64,
+    /// 变化的状态: ac, lid, dock
+    pub state: String,
+    /// 变化后的值 (ac: 接通电源, lid: 打开, dock: 已连接)
+    pub value: bool,
+}
+
+/// 电源、合盖与扩展坞状态
+#[derive(Debug, Clone, Serialize)]
+pub struct PowerStateInfo {
+    /// 是否接通交流电源
+    pub on_ac: Option<bool>,
+    /// 笔记本盖子是否打开
+    pub lid_open: Option<bool>,
+    /// 是否连接扩展坞
+    pub docked: Option<bool>,
+    /// 最近的状态变化事件，按时间升序
+    pub events: Vec<PowerStateEvent>,
+}
+
+/// 交流电源、合盖、扩展坞状态
+type PowerState = (Option<bool>, Option<bool>, Option<bool>);
+
+pub struct PowerStateMonitor {
+    /// 最近一次采样的状态
+    last: PowerState,
+    events: VecDeque<PowerStateEvent>,
+}
+
+impl PowerStateMonitor {
+    /// 创建新的电源状态监控器
+    pub fn new() -> Self {
+        Self {
+            last: Self::read_state(),
+            events: VecDeque::new(),
+        }
+    }
+
+    /// 获取最近一次采样的状态及变化记录，不触发采样
+    pub fn get_info(&self) -> PowerStateInfo {
+        PowerStateInfo {
+            on_ac: self.last.0,
+            lid_open: self.last.1,
+            docked: self.last.2,
+            events: self.events.iter().cloned().collect(),
+        }
+    }
+
+    /// 采样一次当前状态，返回与上一次采样相比新记录的变化事件
+    pub fn sample(&mut self) -> Vec<PowerStateEvent> {
+        let current = Self::read_state();
+        let last = self.last;
+        let mut new_events = Vec::new();
+
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let changes = [
+            ("ac", last.0, current.0),
+            ("lid", last.1, current.1),
+            ("dock", last.2, current.2),
+        ];
+        for (state, before, after) in changes {
+            if let (Some(before), Some(after)) = (before, after) {
+                if before != after {
+                    new_events.push(PowerStateEvent {
+                        timestamp,
+                        state: state.to_string(),
+                        value: after,
+                    });
+                }
+            }
+        }
+        self.last = current;
+
+        for event in &new_events {
+            if self.events.len() == MAX_EVENTS {
+                self.events.pop_front();
+            }
+            self.events.push_back(event.clone());
+        }
+
+        new_events
+    }
+
+    fn read_state() -> PowerState {
+        (Self::read_ac(), Self::read_lid(), Self::read_dock())
+    }
+
+    /// 读取交流电源状态 (/sys/class/power_supply 中 type 为 Mains 的设备)
+    fn read_ac() -> Option<bool> {
+        let entries = fs::read_dir("/sys/class/power_supply").ok()?;
+        let online: Vec<bool> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| read_trimmed(&path.join("type")).as_deref() == Some("Mains"))
+            .filter_map(|path| read_trimmed(&path.join("online")))
+            .map(|online| online == "1")
+            .collect();
+
+        // 台式机通常没有 Mains 设备
+        (!online.is_empty()).then(|| online.contains(&true))
+    }
+
+    /// 读取合盖状态，格式为 "state:      open"
+    fn read_lid() -> Option<bool> {
+        let entries = fs::read_dir("/proc/acpi/button/lid").ok()?;
+        entries
+            .flatten()
+            .find_map(|entry| read_trimmed(&entry.path().join("state")))
+            .map(|state| !state.ends_with("closed"))
+    }
+
+    /// 读取扩展坞状态：ACPI 扩展坞或 Thunderbolt 设备
+    fn read_dock() -> Option<bool> {
+        let acpi_docks: Vec<bool> = fs::read_dir("/sys/devices/platform")
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter(|entry| entry.file_name().to_string_lossy().starts_with("dock."))
+                    .filter_map(|entry| read_trimmed(&entry.path().join("docked")))
+                    .map(|docked| docked == "1")
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // 0-0 为主机控制器自身，其余形如 0-1 的条目为外接设备
+        let thunderbolt = fs::read_dir("/sys/bus/thunderbolt/devices")
+            .ok()
+            .map(|entries| {
+                entries.flatten().any(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    name.split_once('-')
+                        .is_some_and(|(_, route)| route != "0" && !route.contains('.'))
+                })
+            });
+
+        match (acpi_docks.is_empty(), thunderbolt) {
+            (true, None) => None,
+            (_, thunderbolt) => Some(acpi_docks.contains(&true) || thunderbolt == Some(true)),
+        }
+    }
+}
+
+impl Default for PowerStateMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
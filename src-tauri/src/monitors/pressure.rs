@@ -0,0 +1,110 @@
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+
+/// 单行 PSI 统计 (some 或 full)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PressureStats {
+    /// 最近 10 秒内任务阻塞时间占比 (0-100)
+    pub avg10: f64,
+    /// 最近 60 秒内任务阻塞时间占比 (0-100)
+    pub avg60: f64,
+    /// 最近 300 秒内任务阻塞时间占比 (0-100)
+    pub avg300: f64,
+    /// 累计阻塞时间 (微秒)
+    pub total: u64,
+}
+
+/// 单项资源的压力信息
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourcePressure {
+    /// 至少有一个任务因该资源阻塞
+    pub some: PressureStats,
+    /// 所有非空闲任务同时阻塞（旧内核的 CPU 项没有该行）
+    pub full: Option<PressureStats>,
+}
+
+/// 压力阻塞信息 (Linux PSI, /proc/pressure/*)
+#[derive(Debug, Clone, Serialize)]
+pub struct PressureInfo {
+    /// 当前系统是否提供 PSI 数据
+    pub supported: bool,
+    /// CPU 压力
+    pub cpu: Option<ResourcePressure>,
+    /// 内存压力
+    pub memory: Option<ResourcePressure>,
+    /// IO 压力
+    pub io: Option<ResourcePressure>,
+}
+
+pub struct PressureMonitor {
+    base_path: PathBuf,
+}
+
+impl PressureMonitor {
+    /// 创建新的压力监控器
+    pub fn new() -> Self {
+        Self {
+            base_path: PathBuf::from("/proc/pressure"),
+        }
+    }
+
+    /// 获取压力信息
+    pub fn get_info(&mut self) -> PressureInfo {
+        let cpu = self.read_resource("cpu");
+        let memory = self.read_resource("memory");
+        let io = self.read_resource("io");
+
+        PressureInfo {
+            supported: cpu.is_some() || memory.is_some() || io.is_some(),
+            cpu,
+            memory,
+            io,
+        }
+    }
+
+    /// 读取并解析单个资源文件
+    fn read_resource(&self, name: &str) -> Option<ResourcePressure> {
+        let content = fs::read_to_string(self.base_path.join(name)).ok()?;
+
+        let mut some = None;
+        let mut full = None;
+
+        for line in content.lines() {
+            let mut parts = line.split_whitespace();
+            match parts.next() {
+                Some("some") => some = Some(Self::parse_stats(parts)),
+                Some("full") => full = Some(Self::parse_stats(parts)),
+                _ => {}
+            }
+        }
+
+        some.map(|some| ResourcePressure { some, full })
+    }
+
+    /// 解析 "avg10=0.00 avg60=0.00 avg300=0.00 total=0" 格式的字段
+    fn parse_stats<'a>(fields: impl Iterator<Item = &'a str>) -> PressureStats {
+        let mut stats = PressureStats::default();
+
+        for field in fields {
+            let Some((key, value)) = field.split_once('=') else {
+                continue;
+            };
+            match key {
+                "avg10" => stats.avg10 = value.parse().unwrap_or(0.0),
+                "avg60" => stats.avg60 = value.parse().unwrap_or(0.0),
+                "avg300" => stats.avg300 = value.parse().unwrap_or(0.0),
+                "total" => stats.total = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+
+        stats
+    }
+}
+
+impl Default for PressureMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
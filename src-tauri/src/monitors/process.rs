@@ -0,0 +1,179 @@
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use sysinfo::{Process, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::util::{load_json, save_json};
+
+/// 固定进程配置文件名
+const PINNED_FILE: &str = "pinned_processes.json";
+/// 每个进程保留的历史采样数
+const HISTORY_LEN: usize = 300;
+
+/// 固定进程的历史采样点
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessSample {
+    /// 采样时间 (毫秒时间戳)
+    pub timestamp: i64,
+    /// CPU 使用率 (单核为 100)
+    pub cpu_usage: f32,
+    /// 常驻内存 (字节)
+    pub memory: u64,
+}
+
+/// 固定进程信息（同名进程合并统计）
+#[derive(Debug, Clone, Serialize)]
+pub struct PinnedProcess {
+    /// 进程名称
+    pub name: String,
+    /// 是否有实例在运行
+    pub running: bool,
+    /// 匹配到的进程 ID
+    pub pids: Vec<u32>,
+    /// CPU 使用率合计 (单核为 100)
+    pub cpu_usage: f32,
+    /// 常驻内存合计 (字节)
+    pub memory: u64,
+    /// 最近的历史采样
+    pub history: Vec<ProcessSample>,
+}
+
+/// 固定进程信息汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessInfo {
+    /// 所有固定进程
+    pub pinned: Vec<PinnedProcess>,
+}
+
+pub struct ProcessMonitor {
+    system: System,
+    /// 固定的进程名称
+    pinned: Vec<String>,
+    /// 各固定进程的历史采样
+    history: HashMap<String, VecDeque<ProcessSample>>,
+}
+
+impl ProcessMonitor {
+    /// 创建新的进程监控器
+    pub fn new() -> Self {
+        Self {
+            system: System::new(),
+            pinned: Vec::new(),
+            history: HashMap::new(),
+        }
+    }
+
+    /// 获取固定进程信息，并追加一次历史采样
+    pub fn get_info(&mut self) -> ProcessInfo {
+        if self.pinned.is_empty() {
+            return ProcessInfo { pinned: Vec::new() };
+        }
+
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::new()
+                .with_cpu()
+                .with_memory()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .with_cmd(UpdateKind::OnlyIfNotSet),
+        );
+
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let mut pinned = Vec::with_capacity(self.pinned.len());
+
+        for name in &self.pinned {
+            let matches: Vec<_> = self
+                .system
+                .processes()
+                .values()
+                // Linux 上线程也会作为进程列出，只统计真正的进程
+                .filter(|process| process.thread_kind().is_none())
+                .filter(|process| Self::matches_name(process, name))
+                .collect();
+
+            let mut pids: Vec<u32> = matches
+                .iter()
+                .map(|process| process.pid().as_u32())
+                .collect();
+            pids.sort_unstable();
+            let cpu_usage = matches
+                .iter()
+                .fold(0.0, |sum, process| sum + process.cpu_usage());
+            let memory = matches.iter().map(|process| process.memory()).sum();
+
+            let history = self.history.entry(name.clone()).or_default();
+            if history.len() == HISTORY_LEN {
+                history.pop_front();
+            }
+            history.push_back(ProcessSample {
+                timestamp,
+                cpu_usage,
+                memory,
+            });
+
+            pinned.push(PinnedProcess {
+                name: name.clone(),
+                running: !pids.is_empty(),
+                pids,
+                cpu_usage,
+                memory,
+                history: history.iter().cloned().collect(),
+            });
+        }
+
+        ProcessInfo { pinned }
+    }
+
+    /// 进程名称是否匹配
+    ///
+    /// Linux 的进程名 (comm) 被截断为 15 个字符，因此同时比较可执行文件名与 cmd[0] 的文件名
+    fn matches_name(process: &Process, name: &str) -> bool {
+        let file_name = |path: &Path| {
+            path.file_name()
+                .is_some_and(|file| file.to_string_lossy().eq_ignore_ascii_case(name))
+        };
+
+        process.name().to_string_lossy().eq_ignore_ascii_case(name)
+            || process.exe().is_some_and(file_name)
+            || process
+                .cmd()
+                .first()
+                .is_some_and(|arg0| file_name(Path::new(arg0)))
+    }
+
+    /// 设置固定的进程名称，移除不再固定的进程历史
+    pub fn set_pinned(&mut self, names: Vec<String>) {
+        let mut unique: Vec<String> = Vec::new();
+        for name in names {
+            let name = name.trim();
+            if !name.is_empty() && !unique.iter().any(|pinned| pinned == name) {
+                unique.push(name.to_string());
+            }
+        }
+
+        self.history.retain(|name, _| unique.contains(name));
+        self.pinned = unique;
+    }
+
+    /// 当前固定的进程名称
+    pub fn pinned(&self) -> &[String] {
+        &self.pinned
+    }
+}
+
+impl Default for ProcessMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 读取固定进程列表，文件不存在时返回空
+pub fn load_pinned(dir: &Path) -> Result<Vec<String>, String> {
+    load_json(dir, PINNED_FILE, "pinned processes").map(Option::unwrap_or_default)
+}
+
+/// 保存固定进程列表
+pub fn save_pinned(dir: &Path, names: &[String]) -> Result<(), String> {
+    save_json(dir, PINNED_FILE, names, "pinned processes")
+}
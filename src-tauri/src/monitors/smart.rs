@@ -0,0 +1,298 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::time::{Duration, Instant};
+
+use crate::util::{command, run_with_timeout};
+
+/// SMART 数据变化缓慢，且查询可能唤醒休眠的硬盘，缓存一段时间再刷新
+const REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+/// 剩余寿命低于该值时给出警告 (%)
+const LOW_LIFE_PERCENT: u8 = 10;
+/// 单次 smartctl 调用的最长时间，无响应的设备可能让 smartctl 长时间阻塞
+const SMARTCTL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 单个磁盘的 SMART 信息
+#[derive(Debug, Clone, Serialize)]
+pub struct SmartDevice {
+    /// 设备路径 (如 /dev/sda, /dev/nvme0)
+    pub device: String,
+    /// smartctl 设备类型 (如 sat, nvme)
+    pub device_type: String,
+    /// 协议 (ATA, NVMe, SCSI)
+    pub protocol: String,
+    /// 型号
+    pub model: Option<String>,
+    /// 序列号
+    pub serial: Option<String>,
+    /// SMART 整体自检是否通过
+    pub passed: Option<bool>,
+    /// 当前温度 (°C)
+    pub temperature: Option<f32>,
+    /// 通电时间 (小时)
+    pub power_on_hours: Option<u64>,
+    /// 通电次数
+    pub power_cycles: Option<u64>,
+    /// 已重映射扇区数 (ATA 5)
+    pub reallocated_sectors: Option<u64>,
+    /// 待映射扇区数 (ATA 197)
+    pub pending_sectors: Option<u64>,
+    /// 无法修复的扇区数 (ATA 198)
+    pub uncorrectable_sectors: Option<u64>,
+    /// 介质错误数 (NVMe)
+    pub media_errors: Option<u64>,
+    /// 错误日志条目数
+    pub error_log_count: Option<u64>,
+    /// SSD 剩余寿命 (0-100)
+    pub remaining_life_percent: Option<u8>,
+    /// 健康问题提示
+    pub warnings: Vec<String>,
+    /// 读取失败原因（通常是权限不足）
+    pub error: Option<String>,
+    /// 硬盘处于休眠状态，本次未读取
+    pub standby: bool,
+}
+
+/// SMART 信息汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct SmartInfo {
+    /// 是否检测到 smartctl
+    pub available: bool,
+    /// 所有磁盘
+    pub devices: Vec<SmartDevice>,
+    /// 最近一次刷新时间 (毫秒时间戳)
+    pub updated_at: Option<i64>,
+}
+
+pub struct SmartMonitor {
+    available: bool,
+    devices: Vec<SmartDevice>,
+    last_refresh: Option<(Instant, i64)>,
+}
+
+impl SmartMonitor {
+    /// 创建新的 SMART 监控器
+    pub fn new() -> Self {
+        let available = smartctl(&["--version"])
+            .map(|output| output.status.success())
+            .unwrap_or(false);
+
+        Self {
+            available,
+            devices: Vec::new(),
+            last_refresh: None,
+        }
+    }
+
+    /// 缓存是否已过期
+    pub fn needs_refresh(&self) -> bool {
+        self.available
+            && self
+                .last_refresh
+                .is_none_or(|(at, _)| at.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    /// 用新读取的数据更新缓存，休眠中的硬盘保留上一次的数据
+    pub fn update(&mut self, devices: Vec<SmartDevice>) {
+        self.devices = devices
+            .into_iter()
+            .map(|device| {
+                if !device.standby {
+                    return device;
+                }
+                self.devices
+                    .iter()
+                    .find(|last| last.device == device.device && !last.standby)
+                    .map(|last| SmartDevice {
+                        standby: true,
+                        ..last.clone()
+                    })
+                    .unwrap_or(device)
+            })
+            .collect();
+        self.last_refresh = Some((Instant::now(), chrono::Utc::now().timestamp_millis()));
+    }
+
+    /// 获取缓存的 SMART 信息
+    pub fn get_info(&self) -> SmartInfo {
+        SmartInfo {
+            available: self.available,
+            devices: self.devices.clone(),
+            updated_at: self.last_refresh.map(|(_, timestamp)| timestamp),
+        }
+    }
+
+    /// 扫描并读取所有磁盘（阻塞，每块盘调用一次 smartctl）
+    pub fn collect() -> Vec<SmartDevice> {
+        Self::scan()
+            .into_iter()
+            .map(|(device, device_type, protocol)| Self::read_device(device, device_type, protocol))
+            .collect()
+    }
+
+    /// smartctl --scan 列出设备及类型
+    fn scan() -> Vec<(String, String, String)> {
+        let Some(json) = smartctl(&["--scan", "--json"]).and_then(|output| parse_json(&output))
+        else {
+            return Vec::new();
+        };
+
+        json["devices"]
+            .as_array()
+            .map(|devices| {
+                devices
+                    .iter()
+                    .filter_map(|device| {
+                        Some((
+                            device["name"].as_str()?.to_string(),
+                            device["type"].as_str()?.to_string(),
+                            device["protocol"].as_str().unwrap_or("").to_string(),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 读取单块磁盘，-n standby 避免唤醒休眠的硬盘
+    fn read_device(device: String, device_type: String, protocol: String) -> SmartDevice {
+        let mut info = SmartDevice {
+            device,
+            device_type,
+            protocol,
+            model: None,
+            serial: None,
+            passed: None,
+            temperature: None,
+            power_on_hours: None,
+            power_cycles: None,
+            reallocated_sectors: None,
+            pending_sectors: None,
+            uncorrectable_sectors: None,
+            media_errors: None,
+            error_log_count: None,
+            remaining_life_percent: None,
+            warnings: Vec::new(),
+            error: None,
+            standby: false,
+        };
+
+        let output = smartctl(&[
+            "-a",
+            "--json",
+            "-n",
+            "standby",
+            "-d",
+            &info.device_type,
+            &info.device,
+        ]);
+        let Some(json) = output.as_ref().and_then(parse_json) else {
+            info.error = Some("Failed to run smartctl".to_string());
+            return info;
+        };
+
+        // 退出码第 0、1 位表示命令行错误或设备无法打开（通常需要 root 权限）
+        let exit_code = output.and_then(|output| output.status.code()).unwrap_or(0);
+        if exit_code & 0b11 != 0 {
+            let message = json["smartctl"]["messages"][0]["string"]
+                .as_str()
+                .unwrap_or("smartctl could not open the device");
+            if message.contains("STANDBY") {
+                info.standby = true;
+            } else {
+                info.error = Some(message.to_string());
+            }
+            return info;
+        }
+
+        Self::fill(&mut info, &json);
+        info.warnings = Self::warnings(&info);
+        info
+    }
+
+    /// 从 smartctl JSON 中提取字段
+    fn fill(info: &mut SmartDevice, json: &Value) {
+        info.model = json["model_name"].as_str().map(str::to_string);
+        info.serial = json["serial_number"].as_str().map(str::to_string);
+        info.passed = json["smart_status"]["passed"].as_bool();
+        info.temperature = json["temperature"]["current"].as_f64().map(|t| t as f32);
+        info.power_on_hours = json["power_on_time"]["hours"].as_u64();
+        info.power_cycles = json["power_cycle_count"].as_u64();
+
+        // ATA 属性表
+        let attribute = |id: u64| {
+            json["ata_smart_attributes"]["table"]
+                .as_array()?
+                .iter()
+                .find(|attribute| attribute["id"].as_u64() == Some(id))
+        };
+        let raw = |id: u64| attribute(id).and_then(|a| a["raw"]["value"].as_u64());
+        info.reallocated_sectors = raw(5);
+        info.pending_sectors = raw(197);
+        info.uncorrectable_sectors = raw(198);
+        info.error_log_count = json["ata_smart_error_log"]["summary"]["count"].as_u64();
+
+        // SSD 寿命属性的归一化值即剩余百分比: 231 SSD_Life_Left, 202 Percent_Lifetime_Remain,
+        // 233 Media_Wearout_Indicator, 177 Wear_Leveling_Count
+        info.remaining_life_percent = [231, 202, 233, 177]
+            .into_iter()
+            .find_map(|id| attribute(id).and_then(|a| a["value"].as_u64()))
+            .map(|value| value.min(100) as u8);
+
+        // NVMe 健康日志
+        let nvme = &json["nvme_smart_health_information_log"];
+        if nvme.is_object() {
+            info.media_errors = nvme["media_errors"].as_u64();
+            info.error_log_count = nvme["num_err_log_entries"].as_u64();
+            info.remaining_life_percent = nvme["percentage_used"]
+                .as_u64()
+                .map(|used| 100u64.saturating_sub(used) as u8);
+        }
+    }
+
+    /// 根据 SMART 数据生成健康问题提示
+    fn warnings(info: &SmartDevice) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let nonzero = |value: Option<u64>| value.filter(|&v| v > 0);
+
+        if info.passed == Some(false) {
+            warnings.push("SMART 自检未通过，请尽快备份数据".to_string());
+        }
+        if let Some(count) = nonzero(info.reallocated_sectors) {
+            warnings.push(format!("存在 {} 个已重映射扇区", count));
+        }
+        if let Some(count) = nonzero(info.pending_sectors) {
+            warnings.push(format!("存在 {} 个待映射扇区", count));
+        }
+        if let Some(count) = nonzero(info.uncorrectable_sectors) {
+            warnings.push(format!("存在 {} 个无法修复的扇区", count));
+        }
+        if let Some(count) = nonzero(info.media_errors) {
+            warnings.push(format!("发生过 {} 次介质错误", count));
+        }
+        if let Some(life) = info
+            .remaining_life_percent
+            .filter(|&life| life < LOW_LIFE_PERCENT)
+        {
+            warnings.push(format!("SSD 剩余寿命仅 {}%", life));
+        }
+
+        warnings
+    }
+}
+
+impl Default for SmartMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 运行 smartctl，设备无响应导致超时时结束进程并返回 None
+fn smartctl(args: &[&str]) -> Option<std::process::Output> {
+    let mut smartctl = command("smartctl");
+    smartctl.args(args);
+    run_with_timeout(smartctl, SMARTCTL_TIMEOUT)
+}
+
+fn parse_json(output: &std::process::Output) -> Option<Value> {
+    serde_json::from_slice(&output.stdout).ok()
+}
@@ -0,0 +1,432 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::hwmon::HwmonTemperature;
+use crate::util::{command, read_millidegrees, read_trimmed};
+
+/// SoC 状态缓存时间，每次执行 vcgencmd 需要数十毫秒
+const SOC_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 单个温区信息 (/sys/class/thermal/thermal_zone*)
+#[derive(Debug, Clone, Serialize)]
+pub struct ThermalZone {
+    /// 稳定的传感器 ID (如 thermal/x86_pkg_temp)，不随温区编号变化
+    pub id: String,
+    /// 温区名称 (如 thermal_zone0)
+    pub name: String,
+    /// 温区类型 (如 cpu-thermal, soc-thermal, x86_pkg_temp)
+    pub zone_type: String,
+    /// 当前温度 (°C)
+    pub temperature: f32,
+    /// critical 触发点温度 (°C)，超过后系统会强制关机
+    pub critical: Option<f32>,
+    /// 所有触发点，按温度升序
+    pub trip_points: Vec<TripPoint>,
+    /// 尚未达到的最低触发点
+    pub next_trip: Option<TripPoint>,
+    /// 距下一个触发点的余量 (°C)
+    pub next_trip_margin: Option<f32>,
+    /// 是否已越过 passive 触发点（内核开始降频）
+    pub passive_crossed: bool,
+}
+
+/// 温区触发点 (trip_point_N_type / trip_point_N_temp)
+#[derive(Debug, Clone, Serialize)]
+pub struct TripPoint {
+    /// 类型: active, passive, hot, critical
+    pub trip_type: String,
+    /// 触发温度 (°C)
+    pub temperature: f32,
+}
+
+/// 温度排行中的单个传感器
+#[derive(Debug, Clone, Serialize)]
+pub struct HotSensor {
+    /// 稳定的传感器 ID
+    pub id: String,
+    /// 温区名称，hwmon 通道为标签或通道名 (如 Tctl, temp1)
+    pub name: String,
+    /// 温区类型，hwmon 通道为芯片名称 (如 k10temp, nvme)
+    pub zone_type: String,
+    /// 当前温度 (°C)
+    pub temperature: f32,
+    /// critical 触发点温度 (°C)，hwmon 通道为 tempN_crit 或 tempN_max
+    pub critical: Option<f32>,
+    /// 距 critical 触发点的余量 (°C)
+    pub critical_margin: Option<f32>,
+}
+
+/// vcgencmd get_throttled 标志位
+#[derive(Debug, Clone, Serialize)]
+pub struct ThrottleFlags {
+    /// 原始标志值
+    pub raw: u32,
+    /// 当前欠压
+    pub under_voltage: bool,
+    /// 当前 ARM 频率被限制
+    pub frequency_capped: bool,
+    /// 当前正在降频
+    pub throttled: bool,
+    /// 当前达到软温度限制
+    pub soft_temp_limit: bool,
+    /// 自启动以来发生过欠压
+    pub under_voltage_occurred: bool,
+    /// 自启动以来发生过频率限制
+    pub frequency_capped_occurred: bool,
+    /// 自启动以来发生过降频
+    pub throttled_occurred: bool,
+    /// 自启动以来达到过软温度限制
+    pub soft_temp_limit_occurred: bool,
+}
+
+/// ARM SoC 状态 (树莓派 vcgencmd)
+#[derive(Debug, Clone, Serialize)]
+pub struct SocStatus {
+    /// 核心电压 (V)
+    pub core_voltage: Option<f32>,
+    /// 降频/欠压标志
+    pub throttle: Option<ThrottleFlags>,
+}
+
+/// 温度信息汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct ThermalInfo {
+    /// 所有温区
+    pub zones: Vec<ThermalZone>,
+    /// 最高温度 (°C)
+    pub max_temperature: Option<f32>,
+    /// 已越过 passive 触发点的温区 ID
+    pub passive_crossed_zones: Vec<String>,
+    /// SoC 状态（仅在 vcgencmd 可用时提供，最多缓存 5 秒）
+    pub soc: Option<SocStatus>,
+}
+
+pub struct ThermalMonitor {
+    base_path: PathBuf,
+    has_vcgencmd: bool,
+    /// 最近一次读取的 SoC 状态
+    soc: Option<(Instant, SocStatus)>,
+}
+
+impl ThermalMonitor {
+    /// 创建新的温度监控器
+    pub fn new() -> Self {
+        // vcgencmd 只存在于树莓派固件环境，启动时检测一次
+        let has_vcgencmd = command("vcgencmd")
+            .arg("version")
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false);
+
+        Self {
+            base_path: PathBuf::from("/sys/class/thermal"),
+            has_vcgencmd,
+            soc: None,
+        }
+    }
+
+    /// 获取温度信息
+    pub fn get_info(&mut self) -> ThermalInfo {
+        let zones = self.read_zones();
+
+        let max_temperature = zones.iter().map(|zone| zone.temperature).reduce(f32::max);
+        let passive_crossed_zones = zones
+            .iter()
+            .filter(|zone| zone.passive_crossed)
+            .map(|zone| zone.id.clone())
+            .collect();
+
+        let soc = self.read_soc();
+
+        ThermalInfo {
+            zones,
+            max_temperature,
+            passive_crossed_zones,
+            soc,
+        }
+    }
+
+    /// 获取温度最高的 n 个传感器，按温度降序
+    ///
+    /// 合并 thermal 温区与 hwmon 温度通道：k10temp、nvme、drivetemp、amdgpu 等只出现在 hwmon 中。
+    /// 温区本身也会注册为同名 hwmon 芯片 (如 acpitz)，这些芯片予以跳过以免重复；
+    /// x86_pkg_temp 温区与 coretemp 的 Package id N 通道是同一个封装温度，只保留温区
+    pub fn get_hottest(
+        &mut self,
+        n: usize,
+        hwmon_channels: Vec<(String, HwmonTemperature)>,
+    ) -> Vec<HotSensor> {
+        let zones = self.read_zones();
+        let zone_types: Vec<String> = zones.iter().map(|zone| zone.zone_type.clone()).collect();
+        let has_package_zone = zone_types
+            .iter()
+            .any(|zone_type| zone_type == "x86_pkg_temp");
+
+        let zone_sensors = zones.into_iter().map(|zone| HotSensor {
+            critical_margin: zone.critical.map(|critical| critical - zone.temperature),
+            id: zone.id,
+            name: zone.name,
+            zone_type: zone.zone_type,
+            temperature: zone.temperature,
+            critical: zone.critical,
+        });
+        let hwmon_sensors = hwmon_channels
+            .into_iter()
+            .filter(|(chip, _)| !zone_types.contains(chip))
+            .filter(|(chip, temp)| !(has_package_zone && is_coretemp_package(chip, temp)))
+            .map(|(chip, temp)| HotSensor {
+                critical_margin: temp.critical.map(|critical| critical - temp.temperature),
+                id: temp.id,
+                name: temp.label.unwrap_or(temp.channel),
+                zone_type: chip,
+                temperature: temp.temperature,
+                critical: temp.critical,
+            });
+
+        let mut sensors: Vec<HotSensor> = zone_sensors.chain(hwmon_sensors).collect();
+        sensors.sort_by(|a, b| b.temperature.total_cmp(&a.temperature));
+        sensors.truncate(n);
+        sensors
+    }
+
+    /// 读取所有 thermal_zone 温区
+    fn read_zones(&self) -> Vec<ThermalZone> {
+        let Ok(entries) = fs::read_dir(&self.base_path) else {
+            return Vec::new();
+        };
+
+        let mut zones: Vec<ThermalZone> = entries
+            .flatten()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with("thermal_zone")
+            })
+            .filter_map(|entry| Self::read_zone(&entry.path()))
+            .collect();
+
+        // 按编号的数字顺序排序，字符串顺序下 thermal_zone10 会排在 thermal_zone2 之前，
+        // 一旦温区超过 10 个，同类型温区的序号就会错位
+        zones.sort_by_key(|zone| {
+            zone.name
+                .trim_start_matches("thermal_zone")
+                .parse::<u32>()
+                .unwrap_or(u32::MAX)
+        });
+
+        // 温区编号由探测顺序决定，使用类型作为 ID，同类型的温区按顺序追加序号
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for zone in &mut zones {
+            let count = seen.entry(zone.zone_type.clone()).or_insert(0);
+            zone.id = match *count {
+                0 => format!("thermal/{}", zone.zone_type),
+                n => format!("thermal/{}#{}", zone.zone_type, n),
+            };
+            *count += 1;
+        }
+
+        zones
+    }
+
+    /// 读取单个温区，温度文件单位为毫摄氏度
+    fn read_zone(path: &Path) -> Option<ThermalZone> {
+        let temperature = read_millidegrees(&path.join("temp"))?;
+
+        let zone_type = read_trimmed(&path.join("type")).unwrap_or_else(|| "unknown".to_string());
+
+        let trip_points = Self::read_trip_points(path);
+        let critical = trip_points
+            .iter()
+            .find(|trip| trip.trip_type == "critical")
+            .map(|trip| trip.temperature);
+        let next_trip = trip_points
+            .iter()
+            .find(|trip| trip.temperature > temperature)
+            .cloned();
+        let passive_crossed = trip_points
+            .iter()
+            .any(|trip| trip.trip_type == "passive" && temperature >= trip.temperature);
+
+        Some(ThermalZone {
+            id: String::new(),
+            name: path.file_name()?.to_string_lossy().to_string(),
+            zone_type,
+            temperature,
+            critical,
+            next_trip_margin: next_trip
+                .as_ref()
+                .map(|trip| trip.temperature - temperature),
+            next_trip,
+            passive_crossed,
+            trip_points,
+        })
+    }
+
+    /// 读取所有触发点 (trip_point_N_type / trip_point_N_temp)，按温度升序
+    ///
+    /// 未启用的触发点温度为 0 或负值，予以忽略
+    fn read_trip_points(path: &Path) -> Vec<TripPoint> {
+        let mut trip_points: Vec<TripPoint> = (0..)
+            .map(|i| {
+                (
+                    path.join(format!("trip_point_{}_type", i)),
+                    path.join(format!("trip_point_{}_temp", i)),
+                )
+            })
+            .take_while(|(type_path, _)| type_path.exists())
+            .filter_map(|(type_path, temp_path)| {
+                Some(TripPoint {
+                    trip_type: read_trimmed(&type_path)?,
+                    temperature: read_millidegrees(&temp_path).filter(|&t| t > 0.0)?,
+                })
+            })
+            .collect();
+
+        trip_points.sort_by(|a, b| a.temperature.total_cmp(&b.temperature));
+        trip_points
+    }
+
+    /// 读取 SoC 状态，在 SOC_REFRESH_INTERVAL 内复用上一次结果
+    fn read_soc(&mut self) -> Option<SocStatus> {
+        if !self.has_vcgencmd {
+            return None;
+        }
+
+        if let Some((at, soc)) = &self.soc {
+            if at.elapsed() < SOC_REFRESH_INTERVAL {
+                return Some(soc.clone());
+            }
+        }
+
+        let soc = SocStatus {
+            core_voltage: Self::read_core_voltage(),
+            throttle: Self::read_throttle_flags(),
+        };
+        self.soc = Some((Instant::now(), soc.clone()));
+        Some(soc)
+    }
+
+    /// 执行 vcgencmd 并返回 "key=value" 中的 value 部分
+    fn vcgencmd(args: &[&str]) -> Option<String> {
+        let output = command("vcgencmd").args(args).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        stdout
+            .trim()
+            .split_once('=')
+            .map(|(_, value)| value.to_string())
+    }
+
+    /// 读取核心电压，输出格式为 "volt=0.8500V"
+    fn read_core_voltage() -> Option<f32> {
+        Self::vcgencmd(&["measure_volts", "core"])?
+            .trim_end_matches('V')
+            .parse()
+            .ok()
+    }
+
+    /// 读取降频标志，输出格式为 "throttled=0x50005"
+    fn read_throttle_flags() -> Option<ThrottleFlags> {
+        let value = Self::vcgencmd(&["get_throttled"])?;
+        let raw = u32::from_str_radix(value.trim_start_matches("0x"), 16).ok()?;
+        let bit = |n: u32| raw & (1 << n) != 0;
+
+        Some(ThrottleFlags {
+            raw,
+            under_voltage: bit(0),
+            frequency_capped: bit(1),
+            throttled: bit(2),
+            soft_temp_limit: bit(3),
+            under_voltage_occurred: bit(16),
+            frequency_capped_occurred: bit(17),
+            throttled_occurred: bit(18),
+            soft_temp_limit_occurred: bit(19),
+        })
+    }
+}
+
+impl Default for ThermalMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 是否为 coretemp 的封装温度通道 (Package id N)，与 x86_pkg_temp 温区读取同一个传感器
+fn is_coretemp_package(chip: &str, temp: &HwmonTemperature) -> bool {
+    chip == "coretemp"
+        && temp
+            .label
+            .as_deref()
+            .is_some_and(|label| label.starts_with("Package id"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(
+        chip: &str,
+        channel: &str,
+        label: Option<&str>,
+        temperature: f32,
+    ) -> (String, HwmonTemperature) {
+        (
+            chip.to_string(),
+            HwmonTemperature {
+                id: format!("{}/{}", chip, channel),
+                channel: channel.to_string(),
+                label: label.map(str::to_string),
+                temperature,
+                critical: Some(100.0),
+            },
+        )
+    }
+
+    #[test]
+    fn package_temperature_is_listed_once() {
+        let root = std::env::temp_dir().join(format!("skywidget-thermal-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (zone, zone_type, temp) in [
+            ("thermal_zone0", "acpitz", "40000"),
+            ("thermal_zone1", "x86_pkg_temp", "62000"),
+        ] {
+            fs::create_dir_all(root.join(zone)).unwrap();
+            fs::write(root.join(zone).join("type"), zone_type).unwrap();
+            fs::write(root.join(zone).join("temp"), temp).unwrap();
+        }
+        let mut monitor = ThermalMonitor {
+            base_path: root.clone(),
+            has_vcgencmd: false,
+            soc: None,
+        };
+
+        let channels = vec![
+            channel("acpitz", "temp1", None, 40.0),
+            channel("coretemp", "temp1", Some("Package id 0"), 62.0),
+            channel("coretemp", "temp2", Some("Core 0"), 60.0),
+            channel("nvme", "temp1", Some("Composite"), 45.0),
+        ];
+        let ids: Vec<String> = monitor
+            .get_hottest(10, channels)
+            .into_iter()
+            .map(|sensor| sensor.id)
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                "thermal/x86_pkg_temp",
+                "coretemp/temp2",
+                "nvme/temp1",
+                "thermal/acpitz"
+            ]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
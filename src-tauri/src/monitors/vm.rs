@@ -0,0 +1,291 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::util::{command, run_with_timeout};
+
+/// virsh 超时时间，libvirtd 无响应时 virsh 会一直阻塞
+const VIRSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 单个虚拟机信息
+#[derive(Debug, Clone, Serialize)]
+pub struct VmGuest {
+    /// 虚拟机名称
+    pub name: String,
+    /// 状态 (running, paused, shutoff, crashed ...)
+    pub state: String,
+    /// 是否正在运行
+    pub running: bool,
+    /// 分配的 vCPU 数量
+    pub vcpus: u32,
+    /// vCPU 使用率 (0-100，相对于分配的 vCPU)
+    pub cpu_usage: f32,
+    /// 当前内存 (字节)
+    pub memory_current: u64,
+    /// 最大内存 (字节)
+    pub memory_maximum: u64,
+    /// 宿主机上的实际占用 (字节)
+    pub memory_rss: Option<u64>,
+}
+
+/// 虚拟机信息汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct VmInfo {
+    /// 是否检测到 libvirt (virsh)
+    pub available: bool,
+    /// 所有虚拟机
+    pub guests: Vec<VmGuest>,
+    /// 运行中的虚拟机数量
+    pub running_count: usize,
+    /// 未运行的虚拟机数量
+    pub stopped_count: usize,
+}
+
+pub struct VmMonitor {
+    available: bool,
+    /// 最近一次采样得到的虚拟机
+    guests: Vec<VmGuest>,
+    /// 上一次采样的 cpu.time (纳秒)，用于计算使用率
+    last_cpu_time: HashMap<String, (Instant, u64)>,
+}
+
+impl VmMonitor {
+    /// 创建新的虚拟机监控器
+    pub fn new() -> Self {
+        let available = command("virsh")
+            .arg("--version")
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false);
+
+        Self {
+            available,
+            guests: Vec::new(),
+            last_cpu_time: HashMap::new(),
+        }
+    }
+
+    /// 是否需要执行 virsh 采样
+    pub fn needs_refresh(&self) -> bool {
+        self.available
+    }
+
+    /// 通过 virsh domstats 一次性读取所有虚拟机，耗时较长，应在后台线程调用
+    pub fn collect() -> Option<(Instant, String)> {
+        let mut virsh = command("virsh");
+        // 未指定 LIBVIRT_DEFAULT_URI 时连接系统级实例，而不是用户会话
+        if std::env::var_os("LIBVIRT_DEFAULT_URI").is_none() {
+            virsh.args(["-c", "qemu:///system"]);
+        }
+        virsh.args(["domstats", "--state", "--cpu-total", "--balloon", "--vcpu"]);
+
+        let output = run_with_timeout(virsh, VIRSH_TIMEOUT)?;
+        output.status.success().then(|| {
+            (
+                Instant::now(),
+                String::from_utf8_lossy(&output.stdout).to_string(),
+            )
+        })
+    }
+
+    /// 保存后台线程的采样结果并计算 vCPU 使用率
+    pub fn update(&mut self, output: Option<(Instant, String)>) {
+        let Some((now, stdout)) = output else {
+            self.guests.clear();
+            return;
+        };
+
+        self.guests = Self::parse_domstats(&stdout)
+            .into_iter()
+            .map(|(name, stats)| self.build_guest(name, &stats, now))
+            .collect();
+
+        // 清理已删除虚拟机的历史数据
+        let guests = &self.guests;
+        self.last_cpu_time
+            .retain(|name, _| guests.iter().any(|guest| &guest.name == name));
+    }
+
+    /// 获取虚拟机信息
+    pub fn get_info(&self) -> VmInfo {
+        let guests = self.guests.clone();
+        let running_count = guests.iter().filter(|guest| guest.running).count();
+
+        VmInfo {
+            available: self.available,
+            stopped_count: guests.len() - running_count,
+            running_count,
+            guests,
+        }
+    }
+
+    /// 解析 domstats 输出
+    ///
+    /// 格式:
+    /// ```text
+    /// Domain: 'vm1'
+    ///   state.state=1
+    ///   cpu.time=123456789
+    /// ```
+    fn parse_domstats(output: &str) -> Vec<(String, HashMap<String, u64>)> {
+        let mut domains = Vec::new();
+
+        for line in output.lines() {
+            let line = line.trim();
+            if let Some(name) = line.strip_prefix("Domain:") {
+                let name = name.trim().trim_matches('\'').to_string();
+                domains.push((name, HashMap::new()));
+            } else if let Some((key, value)) = line.split_once('=') {
+                if let (Some((_, stats)), Ok(value)) = (domains.last_mut(), value.parse()) {
+                    stats.insert(key.to_string(), value);
+                }
+            }
+        }
+
+        domains
+    }
+
+    /// 构建单个虚拟机信息并计算 vCPU 使用率
+    fn build_guest(&mut self, name: String, stats: &HashMap<String, u64>, now: Instant) -> VmGuest {
+        let get = |key: &str| stats.get(key).copied();
+
+        let state_code = get("state.state").unwrap_or(0);
+        let vcpus = get("vcpu.current").unwrap_or(0) as u32;
+
+        let cpu_usage = match (get("cpu.time"), self.last_cpu_time.get(&name)) {
+            (Some(cpu_time), Some(&(last_time, last_cpu))) if vcpus > 0 => {
+                let elapsed_ns = now.duration_since(last_time).as_nanos() as f64;
+                let used_ns = cpu_time.saturating_sub(last_cpu) as f64;
+                if elapsed_ns > 0.0 {
+                    (used_ns / elapsed_ns / vcpus as f64 * 100.0).min(100.0) as f32
+                } else {
+                    0.0
+                }
+            }
+            _ => 0.0,
+        };
+        if let Some(cpu_time) = get("cpu.time") {
+            self.last_cpu_time.insert(name.clone(), (now, cpu_time));
+        }
+
+        // balloon.* 单位为 KiB
+        VmGuest {
+            state: Self::state_name(state_code).to_string(),
+            running: state_code == 1,
+            vcpus,
+            cpu_usage,
+            memory_current: get("balloon.current").unwrap_or(0) * 1024,
+            memory_maximum: get("balloon.maximum").unwrap_or(0) * 1024,
+            memory_rss: get("balloon.rss").map(|kib| kib * 1024),
+            name,
+        }
+    }
+
+    /// libvirt virDomainState 枚举
+    fn state_name(code: u64) -> &'static str {
+        match code {
+            1 => "running",
+            2 => "blocked",
+            3 => "paused",
+            4 => "shutdown",
+            5 => "shutoff",
+            6 => "crashed",
+            7 => "pmsuspended",
+            _ => "unknown",
+        }
+    }
+}
+
+impl Default for VmMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOMSTATS: &str = "\
+Domain: 'win10'
+  state.state=1
+  state.reason=1
+  cpu.time=2046837513000
+  cpu.user=1614460000000
+  cpu.system=181720000000
+  balloon.current=8388608
+  balloon.maximum=8388608
+  balloon.last-update=0
+  balloon.rss=8421816
+  vcpu.current=4
+  vcpu.maximum=4
+  vcpu.0.state=1
+  vcpu.0.time=512000000000
+  vcpu.0.halted=no
+
+Domain: 'ubuntu server'
+  state.state=5
+  state.reason=1
+  balloon.current=4194304
+  balloon.maximum=4194304
+  vcpu.current=2
+  vcpu.maximum=2
+
+";
+
+    #[test]
+    fn parses_domains_and_numeric_stats() {
+        let domains = VmMonitor::parse_domstats(DOMSTATS);
+
+        assert_eq!(domains.len(), 2);
+        let (name, stats) = &domains[0];
+        assert_eq!(name, "win10");
+        assert_eq!(stats.get("cpu.time"), Some(&2046837513000));
+        assert_eq!(stats.get("balloon.rss"), Some(&8421816));
+        assert_eq!(stats.get("vcpu.current"), Some(&4));
+        // 非数值项 (如 vcpu.N.halted=no) 予以跳过
+        assert_eq!(stats.get("vcpu.0.halted"), None);
+
+        let (name, stats) = &domains[1];
+        assert_eq!(name, "ubuntu server");
+        assert_eq!(stats.get("state.state"), Some(&5));
+        assert_eq!(stats.get("cpu.time"), None);
+    }
+
+    #[test]
+    fn ignores_stats_before_first_domain() {
+        let domains = VmMonitor::parse_domstats("  state.state=1\nDomain: 'vm1'\n  cpu.time=5\n");
+
+        assert_eq!(domains.len(), 1);
+        assert_eq!(domains[0].1.len(), 1);
+    }
+
+    #[test]
+    fn computes_usage_between_samples() {
+        let mut monitor = VmMonitor {
+            available: true,
+            guests: Vec::new(),
+            last_cpu_time: HashMap::new(),
+        };
+        let start = Instant::now();
+        let sample = |cpu_time: u64| {
+            format!("Domain: 'vm1'\n  state.state=1\n  cpu.time={}\n  vcpu.current=2\n  balloon.current=1024\n", cpu_time)
+        };
+
+        monitor.update(Some((start, sample(0))));
+        assert_eq!(monitor.get_info().guests[0].cpu_usage, 0.0);
+
+        // 1 秒内用掉 1 秒 CPU 时间，2 个 vCPU 中占 50%
+        monitor.update(Some((
+            start + Duration::from_secs(1),
+            sample(1_000_000_000),
+        )));
+        let info = monitor.get_info();
+        assert_eq!(info.running_count, 1);
+        assert_eq!(info.guests[0].cpu_usage, 50.0);
+        assert_eq!(info.guests[0].memory_current, 1024 * 1024);
+
+        monitor.update(None);
+        assert!(monitor.get_info().guests.is_empty());
+    }
+}
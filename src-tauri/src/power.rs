@@ -0,0 +1,241 @@
+// CPU 调速器 (Linux) / 电源计划 (Windows) 的读取与切换
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::util::{command, read_trimmed};
+
+/// 单个电源计划或调速器
+#[derive(Debug, Clone, Serialize)]
+pub struct PowerPlan {
+    /// 切换时使用的标识 (Linux 为调速器名称，Windows 为方案 GUID)
+    pub id: String,
+    /// 显示名称
+    pub name: String,
+    /// 是否为当前生效的计划
+    pub active: bool,
+}
+
+/// 电源计划信息
+#[derive(Debug, Clone, Serialize)]
+pub struct PowerPlanInfo {
+    /// 使用的后端: cpufreq / powercfg / unsupported
+    pub backend: String,
+    /// 可用的计划
+    pub plans: Vec<PowerPlan>,
+}
+
+/// 获取当前可用的电源计划
+pub fn get_power_plans() -> PowerPlanInfo {
+    if cfg!(target_os = "linux") {
+        PowerPlanInfo {
+            backend: "cpufreq".to_string(),
+            plans: cpufreq_governors(),
+        }
+    } else if cfg!(target_os = "windows") {
+        PowerPlanInfo {
+            backend: "powercfg".to_string(),
+            plans: powercfg_schemes(),
+        }
+    } else {
+        PowerPlanInfo {
+            backend: "unsupported".to_string(),
+            plans: Vec::new(),
+        }
+    }
+}
+
+/// 切换电源计划（需要管理员/root 权限）
+pub fn set_power_plan(id: &str) -> Result<(), String> {
+    let info = get_power_plans();
+    if !info.plans.iter().any(|plan| plan.id == id) {
+        return Err(format!("Unknown power plan: {}", id));
+    }
+
+    if cfg!(target_os = "linux") {
+        set_cpufreq_governor(id)
+    } else if cfg!(target_os = "windows") {
+        set_powercfg_scheme(id)
+    } else {
+        Err("Power plan switching is not supported on this platform".to_string())
+    }
+}
+
+const CPU_SYSFS: &str = "/sys/devices/system/cpu";
+
+/// 读取 cpu0 的可用调速器及当前调速器
+fn cpufreq_governors() -> Vec<PowerPlan> {
+    let cpufreq = Path::new(CPU_SYSFS).join("cpu0/cpufreq");
+    let current = read_trimmed(&cpufreq.join("scaling_governor")).unwrap_or_default();
+
+    read_trimmed(&cpufreq.join("scaling_available_governors"))
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|governor| PowerPlan {
+            id: governor.to_string(),
+            name: governor.to_string(),
+            active: governor == current,
+        })
+        .collect()
+}
+
+/// 为所有核心设置调速器
+fn set_cpufreq_governor(governor: &str) -> Result<(), String> {
+    write_governors(Path::new(CPU_SYSFS), governor)
+}
+
+/// 写入各核心的调速器，任一核心失败时将已写入的核心恢复为原调速器，
+/// 并在错误中列出失败的核心
+fn write_governors(cpu_sysfs: &Path, governor: &str) -> Result<(), String> {
+    let entries = fs::read_dir(cpu_sysfs)
+        .map_err(|e| format!("Failed to read {}: {}", cpu_sysfs.display(), e))?;
+
+    let mut cpus: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let path = entry.path().join("cpufreq/scaling_governor");
+            (name.starts_with("cpu") && path.exists()).then_some((name, path))
+        })
+        .collect();
+    cpus.sort_by_key(|(name, _)| name[3..].parse::<u32>().unwrap_or(u32::MAX));
+
+    let mut written = Vec::new();
+    let mut failed = Vec::new();
+    let mut permission_denied = true;
+    for (name, path) in &cpus {
+        let previous = read_trimmed(path);
+        match fs::write(path, governor) {
+            Ok(()) => written.push((path, previous)),
+            Err(e) => {
+                permission_denied &= e.kind() == std::io::ErrorKind::PermissionDenied;
+                failed.push(format!("{} ({})", name, e));
+            }
+        }
+    }
+
+    if failed.is_empty() {
+        return Ok(());
+    }
+
+    for (path, previous) in written {
+        if let Some(previous) = previous {
+            let _ = fs::write(path, previous);
+        }
+    }
+
+    if permission_denied && failed.len() == cpus.len() {
+        Err("Changing the CPU governor requires root privileges".to_string())
+    } else {
+        Err(format!(
+            "Failed to set governor {} on {}; other CPUs were restored",
+            governor,
+            failed.join(", ")
+        ))
+    }
+}
+
+/// 解析 `powercfg /list` 输出
+///
+/// 示例: `Power Scheme GUID: 381b4222-f694-41f0-9685-ff5bb260df2e  (Balanced) *`
+/// 输出会随系统语言变化，因此只依赖 GUID、括号和星号
+fn powercfg_schemes() -> Vec<PowerPlan> {
+    let Some(output) = run_powercfg(&["/list"]) else {
+        return Vec::new();
+    };
+
+    output
+        .lines()
+        .filter_map(|line| {
+            let id = line.split_whitespace().find(|token| is_guid(token))?;
+            let name = line
+                .split_once('(')
+                .and_then(|(_, rest)| rest.split_once(')'))
+                .map(|(name, _)| name.to_string())
+                .unwrap_or_else(|| id.to_string());
+
+            Some(PowerPlan {
+                id: id.to_string(),
+                name,
+                active: line.trim_end().ends_with('*'),
+            })
+        })
+        .collect()
+}
+
+fn set_powercfg_scheme(guid: &str) -> Result<(), String> {
+    run_powercfg(&["/setactive", guid])
+        .map(|_| ())
+        .ok_or_else(|| format!("Failed to activate power scheme {}", guid))
+}
+
+/// 运行 powercfg，成功时返回标准输出
+fn run_powercfg(args: &[&str]) -> Option<String> {
+    let output = command("powercfg").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// 判断是否为 8-4-4-4-12 格式的 GUID
+fn is_guid(token: &str) -> bool {
+    let parts: Vec<&str> = token.split('-').collect();
+    parts.len() == 5
+        && parts
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(part, len)| part.len() == len && part.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在临时目录中模拟 /sys/devices/system/cpu
+    fn fake_cpu_sysfs(name: &str, cpus: usize) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("skywidget-power-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for cpu in 0..cpus {
+            let cpufreq = root.join(format!("cpu{}/cpufreq", cpu));
+            fs::create_dir_all(&cpufreq).unwrap();
+            fs::write(cpufreq.join("scaling_governor"), "schedutil\n").unwrap();
+        }
+        fs::create_dir_all(root.join("cpuidle")).unwrap();
+        root
+    }
+
+    fn governors(root: &Path, cpus: usize) -> Vec<String> {
+        (0..cpus)
+            .map(|cpu| {
+                read_trimmed(&root.join(format!("cpu{}/cpufreq/scaling_governor", cpu)))
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn writes_all_cpus() {
+        let root = fake_cpu_sysfs("all", 3);
+        assert!(write_governors(&root, "performance").is_ok());
+        assert_eq!(governors(&root, 3), ["performance"; 3]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn restores_written_cpus_on_failure() {
+        let root = fake_cpu_sysfs("rollback", 4);
+        // 调速器文件为目录时写入失败
+        let broken = root.join("cpu2/cpufreq/scaling_governor");
+        fs::remove_file(&broken).unwrap();
+        fs::create_dir(&broken).unwrap();
+
+        let err = write_governors(&root, "performance").unwrap_err();
+        assert!(err.contains("cpu2"), "{}", err);
+        assert!(!err.contains("cpu1"), "{}", err);
+        assert_eq!(governors(&root, 2), ["schedutil"; 2]);
+        assert_eq!(governors(&root, 4)[3], "schedutil");
+        fs::remove_dir_all(root).unwrap();
+    }
+}
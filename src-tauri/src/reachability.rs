@@ -0,0 +1,435 @@
+// 网络可达性探测：对配置的目标进行 TCP / DNS / HTTP 探测，统计延迟与丢包
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::util::{load_json, save_json};
+
+/// 探测目标配置文件名
+const TARGETS_FILE: &str = "reachability_targets.json";
+/// 单次连接超时
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+/// 所有探测的总时限，DNS 解析无法设置超时，由此兜底
+const PROBE_DEADLINE: Duration = Duration::from_secs(8);
+/// TCP 探测的尝试次数，用于计算丢包率
+const TCP_ATTEMPTS: u32 = 3;
+
+/// 探测方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbeKind {
+    /// TCP 连接 (host:port)
+    Tcp,
+    /// 域名解析 (host)
+    Dns,
+    /// HTTP 请求 (http://host[:port]/path，IPv6 地址写作 [::1])
+    Http,
+}
+
+/// 探测目标
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProbeTarget {
+    /// 显示名称
+    pub name: String,
+    /// 探测方式
+    pub kind: ProbeKind,
+    /// 目标地址
+    pub target: String,
+}
+
+/// 单个目标的探测结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbeResult {
+    /// 显示名称
+    pub name: String,
+    /// 探测方式
+    pub kind: ProbeKind,
+    /// 目标地址
+    pub target: String,
+    /// 是否可达
+    pub reachable: bool,
+    /// 平均延迟 (毫秒)
+    pub latency_ms: Option<f64>,
+    /// 丢包率 (0-100)，即失败尝试的比例
+    pub packet_loss_percent: f64,
+    /// 失败原因
+    pub error: Option<String>,
+}
+
+/// 可达性探测汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct ReachabilityInfo {
+    /// 各目标结果
+    pub results: Vec<ProbeResult>,
+    /// 是否至少有一个目标可达
+    pub internet_reachable: bool,
+    /// 探测时间 (毫秒时间戳)
+    pub timestamp: i64,
+}
+
+/// 默认探测目标
+pub fn default_targets() -> Vec<ProbeTarget> {
+    vec![
+        ProbeTarget {
+            name: "Cloudflare DNS".to_string(),
+            kind: ProbeKind::Tcp,
+            target: "1.1.1.1:53".to_string(),
+        },
+        ProbeTarget {
+            name: "Google DNS".to_string(),
+            kind: ProbeKind::Tcp,
+            target: "8.8.8.8:53".to_string(),
+        },
+        ProbeTarget {
+            name: "DNS resolution".to_string(),
+            kind: ProbeKind::Dns,
+            target: "example.com".to_string(),
+        },
+    ]
+}
+
+/// 并行探测所有目标（阻塞，最长 PROBE_DEADLINE）
+pub fn probe_all(targets: &[ProbeTarget]) -> ReachabilityInfo {
+    let (tx, rx) = mpsc::channel();
+    for (index, target) in targets.iter().cloned().enumerate() {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            let _ = tx.send((index, probe(&target)));
+        });
+    }
+    drop(tx);
+
+    let mut results: Vec<Option<ProbeResult>> = vec![None; targets.len()];
+    let deadline = Instant::now() + PROBE_DEADLINE;
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        match rx.recv_timeout(remaining) {
+            Ok((index, result)) => results[index] = Some(result),
+            Err(_) => break,
+        }
+    }
+
+    let results: Vec<ProbeResult> = results
+        .into_iter()
+        .zip(targets)
+        .map(|(result, target)| {
+            result.unwrap_or_else(|| failed(target, "Probe timed out".to_string()))
+        })
+        .collect();
+
+    ReachabilityInfo {
+        internet_reachable: results.iter().any(|result| result.reachable),
+        results,
+        timestamp: chrono::Utc::now().timestamp_millis(),
+    }
+}
+
+/// 探测单个目标
+fn probe(target: &ProbeTarget) -> ProbeResult {
+    match target.kind {
+        ProbeKind::Tcp => probe_tcp(target),
+        ProbeKind::Dns => probe_dns(target),
+        ProbeKind::Http => probe_http(target),
+    }
+}
+
+/// 多次建立 TCP 连接，统计平均延迟与失败比例
+fn probe_tcp(target: &ProbeTarget) -> ProbeResult {
+    let addr = match resolve(&target.target) {
+        Ok(addr) => addr,
+        Err(e) => return failed(target, e),
+    };
+
+    let mut latencies = Vec::new();
+    let mut last_error = None;
+    for _ in 0..TCP_ATTEMPTS {
+        let start = Instant::now();
+        match TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT) {
+            Ok(_) => latencies.push(elapsed_ms(start)),
+            Err(e) => last_error = Some(e.to_string()),
+        }
+    }
+
+    let failures = TCP_ATTEMPTS - latencies.len() as u32;
+    ProbeResult {
+        name: target.name.clone(),
+        kind: target.kind,
+        target: target.target.clone(),
+        reachable: !latencies.is_empty(),
+        latency_ms: (!latencies.is_empty())
+            .then(|| latencies.iter().sum::<f64>() / latencies.len() as f64),
+        packet_loss_percent: failures as f64 / TCP_ATTEMPTS as f64 * 100.0,
+        error: last_error.filter(|_| latencies.is_empty()),
+    }
+}
+
+/// 通过系统解析器解析域名
+fn probe_dns(target: &ProbeTarget) -> ProbeResult {
+    let start = Instant::now();
+    match (target.target.as_str(), 0)
+        .to_socket_addrs()
+        .map(|mut addrs| addrs.next())
+    {
+        Ok(Some(_)) => succeeded(target, elapsed_ms(start)),
+        Ok(None) => failed(target, "No addresses returned".to_string()),
+        Err(e) => failed(target, e.to_string()),
+    }
+}
+
+/// 发送 HEAD 请求，收到状态行即视为可达
+///
+/// 只支持明文 HTTP；https 地址仅检测 443 端口的 TCP 连接
+fn probe_http(target: &ProbeTarget) -> ProbeResult {
+    let url = match HttpUrl::parse(&target.target) {
+        Ok(url) => url,
+        Err(e) => return failed(target, e),
+    };
+
+    let start = Instant::now();
+    let result = resolve(&url.authority()).and_then(|addr| {
+        let mut stream =
+            TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT).map_err(|e| e.to_string())?;
+        if url.https {
+            return Ok(());
+        }
+
+        stream
+            .set_read_timeout(Some(CONNECT_TIMEOUT))
+            .map_err(|e| e.to_string())?;
+        let request = format!(
+            "HEAD {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+            url.path,
+            url.host_header()
+        );
+        stream
+            .write_all(request.as_bytes())
+            .map_err(|e| e.to_string())?;
+
+        let mut status = String::new();
+        BufReader::new(stream)
+            .read_line(&mut status)
+            .map_err(|e| e.to_string())?;
+        if status.starts_with("HTTP/") {
+            Ok(())
+        } else {
+            Err("Invalid HTTP response".to_string())
+        }
+    });
+
+    match result {
+        Ok(()) => succeeded(target, elapsed_ms(start)),
+        Err(e) => failed(target, e),
+    }
+}
+
+/// HTTP 探测地址 ([http[s]://][user@]host[:port][/path][?query][#fragment])
+#[derive(Debug, PartialEq)]
+struct HttpUrl {
+    https: bool,
+    /// 主机名或 IP，IPv6 地址不含方括号
+    host: String,
+    port: u16,
+    /// 请求路径，含查询参数
+    path: String,
+}
+
+impl HttpUrl {
+    fn parse(url: &str) -> Result<Self, String> {
+        let url = url.trim();
+        let (https, rest) = match url.split_once("://") {
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("https") => (true, rest),
+            Some((scheme, rest)) if scheme.eq_ignore_ascii_case("http") => (false, rest),
+            Some((scheme, _)) => return Err(format!("Unsupported URL scheme: {}", scheme)),
+            None => (false, url),
+        };
+
+        let rest = rest.split('#').next().unwrap_or_default();
+        let (authority, path) = match rest.find(['/', '?']) {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        let path = if path.starts_with('?') {
+            format!("/{}", path)
+        } else {
+            path.to_string()
+        };
+        let authority = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host)| host);
+
+        // IPv6 地址必须写在方括号内，如 http://[::1]:8080/
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, after) = bracketed
+                    .split_once(']')
+                    .ok_or_else(|| format!("Invalid IPv6 address in URL: {}", url))?;
+                match after {
+                    "" => (host, None),
+                    _ => match after.strip_prefix(':') {
+                        Some(port) => (host, Some(port)),
+                        None => return Err(format!("Invalid URL: {}", url)),
+                    },
+                }
+            }
+            None => match authority.split_once(':') {
+                Some((_, port)) if port.contains(':') => {
+                    return Err(format!("IPv6 address must be in brackets: {}", url))
+                }
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            },
+        };
+
+        if host.is_empty() {
+            return Err(format!("Missing host in URL: {}", url));
+        }
+        let port = match port {
+            Some(port) => port
+                .parse()
+                .map_err(|_| format!("Invalid port in URL: {}", url))?,
+            None if https => 443,
+            None => 80,
+        };
+
+        Ok(Self {
+            https,
+            host: host.to_string(),
+            port,
+            path,
+        })
+    }
+
+    /// 主机名，IPv6 地址加上方括号
+    fn bracketed_host(&self) -> String {
+        if self.host.contains(':') {
+            format!("[{}]", self.host)
+        } else {
+            self.host.clone()
+        }
+    }
+
+    /// 用于连接的 host:port
+    fn authority(&self) -> String {
+        format!("{}:{}", self.bracketed_host(), self.port)
+    }
+
+    /// Host 请求头，默认端口时省略端口
+    fn host_header(&self) -> String {
+        let default_port = if self.https { 443 } else { 80 };
+        if self.port == default_port {
+            self.bracketed_host()
+        } else {
+            self.authority()
+        }
+    }
+}
+
+/// 解析 host:port 为第一个可用地址
+fn resolve(addr: &str) -> Result<SocketAddr, String> {
+    addr.to_socket_addrs()
+        .map_err(|e| format!("Failed to resolve {}: {}", addr, e))?
+        .next()
+        .ok_or_else(|| format!("No addresses for {}", addr))
+}
+
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+fn succeeded(target: &ProbeTarget, latency_ms: f64) -> ProbeResult {
+    ProbeResult {
+        name: target.name.clone(),
+        kind: target.kind,
+        target: target.target.clone(),
+        reachable: true,
+        latency_ms: Some(latency_ms),
+        packet_loss_percent: 0.0,
+        error: None,
+    }
+}
+
+fn failed(target: &ProbeTarget, error: String) -> ProbeResult {
+    ProbeResult {
+        name: target.name.clone(),
+        kind: target.kind,
+        target: target.target.clone(),
+        reachable: false,
+        latency_ms: None,
+        packet_loss_percent: 100.0,
+        error: Some(error),
+    }
+}
+
+/// 读取探测目标，未配置时返回默认目标
+pub fn load_targets(dir: &Path) -> Result<Vec<ProbeTarget>, String> {
+    load_json(dir, TARGETS_FILE, "reachability targets")
+        .map(|targets| targets.unwrap_or_else(default_targets))
+}
+
+/// 保存探测目标
+pub fn save_targets(dir: &Path, targets: &[ProbeTarget]) -> Result<(), String> {
+    if targets.iter().any(|target| target.target.trim().is_empty()) {
+        return Err("Reachability target address must not be empty".to_string());
+    }
+
+    save_json(dir, TARGETS_FILE, targets, "reachability targets")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(host: &str, port: u16, path: &str) -> HttpUrl {
+        HttpUrl {
+            https: false,
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_host_port_and_path() {
+        assert_eq!(
+            HttpUrl::parse("http://example.com").unwrap(),
+            url("example.com", 80, "/")
+        );
+        assert_eq!(
+            HttpUrl::parse("router.lan:8080/status?full=1#top").unwrap(),
+            url("router.lan", 8080, "/status?full=1")
+        );
+        assert_eq!(
+            HttpUrl::parse("http://admin@192.168.1.1?page=wan").unwrap(),
+            url("192.168.1.1", 80, "/?page=wan")
+        );
+
+        let https = HttpUrl::parse("HTTPS://example.com/health").unwrap();
+        assert!(https.https);
+        assert_eq!(https.port, 443);
+    }
+
+    #[test]
+    fn brackets_ipv6_hosts() {
+        let parsed = HttpUrl::parse("http://[::1]:8080/metrics").unwrap();
+        assert_eq!(parsed, url("::1", 8080, "/metrics"));
+        assert_eq!(parsed.authority(), "[::1]:8080");
+        assert_eq!(parsed.host_header(), "[::1]:8080");
+
+        let parsed = HttpUrl::parse("http://[2001:db8::1]").unwrap();
+        assert_eq!(parsed.authority(), "[2001:db8::1]:80");
+        assert_eq!(parsed.host_header(), "[2001:db8::1]");
+    }
+
+    #[test]
+    fn rejects_invalid_urls() {
+        assert!(HttpUrl::parse("ftp://example.com").is_err());
+        assert!(HttpUrl::parse("http://::1/").is_err());
+        assert!(HttpUrl::parse("http://[::1/").is_err());
+        assert!(HttpUrl::parse("http://[::1]x/").is_err());
+        assert!(HttpUrl::parse("http://example.com:http/").is_err());
+        assert!(HttpUrl::parse("http:///path").is_err());
+    }
+}
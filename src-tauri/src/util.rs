@@ -0,0 +1,131 @@
+// 通用辅助函数：sysfs 读取、外部命令与应用数据目录中的 JSON 配置
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 读取 sysfs 文件并去除首尾空白，空文件视为不存在
+pub fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// 读取毫摄氏度文件并转换为摄氏度
+pub fn read_millidegrees(path: &Path) -> Option<f32> {
+    let millidegrees: i64 = read_trimmed(path)?.parse().ok()?;
+    Some(millidegrees as f32 / 1000.0)
+}
+
+/// 创建外部命令，避免 GUI 程序调用时在 Windows 上弹出控制台窗口
+pub fn command(program: &str) -> Command {
+    let command = Command::new(program);
+
+    #[cfg(windows)]
+    let command = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        let mut command = command;
+        command.creation_flags(CREATE_NO_WINDOW);
+        command
+    };
+
+    command
+}
+
+/// 执行命令并读取标准输出，超时时结束进程并返回 None
+///
+/// 返回的 Output 不包含标准错误，退出码由调用方判断
+pub fn run_with_timeout(mut command: Command, timeout: Duration) -> Option<Output> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    // 在单独线程中读取输出，避免输出较多时填满管道导致子进程阻塞
+    let mut stdout = child.stdout.take()?;
+    let reader = thread::spawn(move || {
+        let mut output = Vec::new();
+        stdout.read_to_end(&mut output).map(|_| output)
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(50)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+        }
+    };
+
+    let stdout = reader.join().ok()?.ok()?;
+    Some(Output {
+        status: status?,
+        stdout,
+        stderr: Vec::new(),
+    })
+}
+
+/// 读取应用数据目录中的 JSON 配置，文件不存在时返回 None
+///
+/// `what` 用于错误信息，如 "pinned processes"
+pub fn load_json<T: DeserializeOwned>(
+    dir: &Path,
+    file: &str,
+    what: &str,
+) -> Result<Option<T>, String> {
+    let path = dir.join(file);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", what, e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Invalid {} file: {}", what, e))
+}
+
+/// 将配置保存为应用数据目录中的 JSON 文件
+pub fn save_json<T: Serialize + ?Sized>(
+    dir: &Path,
+    file: &str,
+    value: &T,
+    what: &str,
+) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", what, e))?;
+    fs::write(dir.join(file), content).map_err(|e| format!("Failed to save {}: {}", what, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn kills_command_after_timeout() {
+        let mut echo = Command::new("echo");
+        echo.arg("ok");
+        let output = run_with_timeout(echo, Duration::from_secs(5)).unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"ok\n");
+
+        let mut sleep = Command::new("sleep");
+        sleep.arg("10");
+        let start = Instant::now();
+        assert!(run_with_timeout(sleep, Duration::from_millis(200)).is_none());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,4 +1,5 @@
 import { invoke } from '@tauri-apps/api/core'
+import { listen, type UnlistenFn } from '@tauri-apps/api/event'
 
 /**
  * CPU 信息接口
@@ -9,6 +10,9 @@ export interface CpuInfo {
   usage: number
   core_usage: number[]
   frequency: number
+  context_switches_per_sec: number
+  interrupts_per_sec: number
+  steal_percent: number
 }
 
 /**
@@ -22,6 +26,22 @@ export interface MemoryInfo {
   swap_total: number
   swap_used: number
   swap_usage_percent: number
+  cached: number
+  buffers: number
+  dirty: number
+  writeback: number
+  page_faults_per_sec: number
+  major_faults_per_sec: number
+  swap_in_per_sec: number
+  swap_out_per_sec: number
+  ecc: EccErrors | null
+}
+
+export interface EccErrors {
+  correctable: number
+  uncorrectable: number
+  correctable_last_hour: number | null
+  uncorrectable_last_hour: number | null
 }
 
 /**
@@ -36,14 +56,479 @@ export interface DiskInfo {
   used_space: number
   usage_percent: number
   is_removable: boolean
+  is_read_only: boolean
+  remounted_read_only: boolean
+  excluded: boolean
+}
+
+export interface DriveTemperature {
+  device: string
+  model: string | null
+  temperature: number
+  sensor_id: string
 }
 
 export interface DisksInfo {
   disks: DiskInfo[]
   disk_count: number
+  included_count: number
   total_space: number
   total_used: number
   total_available: number
+  fs_readonly_count: number
+  drive_temperatures: DriveTemperature[]
+}
+
+/**
+ * 资源压力信息接口 (Linux PSI)
+ */
+export interface PressureStats {
+  avg10: number
+  avg60: number
+  avg300: number
+  total: number
+}
+
+export interface ResourcePressure {
+  some: PressureStats
+  full: PressureStats | null
+}
+
+export interface PressureInfo {
+  supported: boolean
+  cpu: ResourcePressure | null
+  memory: ResourcePressure | null
+  io: ResourcePressure | null
+}
+
+/**
+ * 温区触发点接口
+ */
+export interface TripPoint {
+  trip_type: string
+  temperature: number
+}
+
+/**
+ * 温度信息接口
+ */
+export interface ThermalZone {
+  id: string
+  name: string
+  zone_type: string
+  temperature: number
+  critical: number | null
+  trip_points: TripPoint[]
+  next_trip: TripPoint | null
+  next_trip_margin: number | null
+  passive_crossed: boolean
+}
+
+export interface HotSensor {
+  id: string
+  name: string
+  zone_type: string
+  temperature: number
+  critical: number | null
+  critical_margin: number | null
+}
+
+export interface ThrottleFlags {
+  raw: number
+  under_voltage: boolean
+  frequency_capped: boolean
+  throttled: boolean
+  soft_temp_limit: boolean
+  under_voltage_occurred: boolean
+  frequency_capped_occurred: boolean
+  throttled_occurred: boolean
+  soft_temp_limit_occurred: boolean
+}
+
+export interface SocStatus {
+  core_voltage: number | null
+  throttle: ThrottleFlags | null
+}
+
+export interface ThermalInfo {
+  zones: ThermalZone[]
+  max_temperature: number | null
+  passive_crossed_zones: string[]
+  soc: SocStatus | null
+}
+
+/**
+ * GPU 信息接口
+ */
+export interface GpuDevice {
+  name: string
+  backend: string
+  utilization: number | null
+  frequency: number | null
+  power: number | null
+}
+
+export interface PowerRail {
+  name: string
+  current_mw: number
+  average_mw: number
+}
+
+export interface JetsonStats {
+  emc_usage: number | null
+  emc_frequency: number | null
+  ram_used: number | null
+  ram_total: number | null
+  rails: PowerRail[]
+}
+
+export interface GpuInfo {
+  gpus: GpuDevice[]
+  jetson: JetsonStats | null
+}
+
+/**
+ * hwmon 温度通道接口
+ */
+export interface HwmonTemperature {
+  id: string
+  channel: string
+  label: string | null
+  temperature: number
+  critical: number | null
+}
+
+export interface HwmonFan {
+  id: string
+  channel: string
+  label: string | null
+  rpm: number | null
+  pwm_percent: number | null
+}
+
+/**
+ * hwmon 传感器信息接口
+ */
+export interface HwmonChip {
+  id: string
+  name: string
+  path: string
+  temp_inputs: number
+  fan_inputs: number
+  voltage_inputs: number
+  temperatures: HwmonTemperature[]
+  fans: HwmonFan[]
+}
+
+export type SensorDriverState = 'available' | 'driver_missing' | 'driver_loaded_no_sensors' | 'unknown'
+
+export interface HwmonInfo {
+  chips: HwmonChip[]
+  board_vendor: string | null
+  board_name: string | null
+  driver_state: SensorDriverState
+  suggested_module: string | null
+  hint: string | null
+}
+
+/**
+ * 崩溃报告接口
+ */
+export interface CrashReport {
+  timestamp: number
+  version: string
+  os: string
+  thread: string
+  message: string
+  location: string | null
+  backtrace: string
+}
+
+/**
+ * 磁盘基准测试结果接口
+ */
+export interface DiskBenchmarkResult {
+  path: string
+  file_size: number
+  seq_write_mbps: number
+  seq_read_mbps: number
+  rand_write_iops: number
+  rand_read_iops: number
+  duration_ms: number
+  timestamp: number
+}
+
+/**
+ * 压力测试报告接口
+ */
+export interface StressSample {
+  elapsed_ms: number
+  under_load: boolean
+  cpu_usage: number
+  frequency: number
+  max_temperature: number | null
+}
+
+export interface StressTestReport {
+  duration_secs: number
+  threads: number
+  memory_load: number
+  idle_temperature: number | null
+  peak_temperature: number | null
+  temperature_rise: number | null
+  cooldown_temperature: number | null
+  average_frequency: number
+  min_frequency: number
+  max_frequency: number
+  frequency_drop_percent: number
+  samples: StressSample[]
+  timestamp: number
+}
+
+/**
+ * 高频采集数据接口
+ */
+export interface CaptureSample {
+  timestamp: number
+  cpu_usage: number
+  core_usage: number[]
+  cpu_frequency: number
+  memory_usage_percent: number
+  swap_usage_percent: number
+  thermal_zones: ThermalZone[]
+}
+
+export interface CaptureData {
+  active: boolean
+  started_at: number | null
+  ends_at: number | null
+  samples: CaptureSample[]
+}
+
+/**
+ * 电源计划接口
+ */
+export interface PowerPlan {
+  id: string
+  name: string
+  active: boolean
+}
+
+export interface PowerPlanInfo {
+  backend: 'cpufreq' | 'powercfg' | 'unsupported'
+  plans: PowerPlan[]
+}
+
+/**
+ * 仪表盘布局接口
+ */
+export interface DashboardWidget {
+  id: string
+  widget_type: string
+  metrics: string[]
+  time_range: number | null
+  x: number
+  y: number
+  width: number
+  height: number
+}
+
+export interface DashboardLayout {
+  view: string
+  widgets: DashboardWidget[]
+  updated_at: number
+}
+
+/**
+ * 虚拟机信息接口 (libvirt)
+ */
+export interface VmGuest {
+  name: string
+  state: string
+  running: boolean
+  vcpus: number
+  cpu_usage: number
+  memory_current: number
+  memory_maximum: number
+  memory_rss: number | null
+}
+
+export interface VmInfo {
+  available: boolean
+  guests: VmGuest[]
+  running_count: number
+  stopped_count: number
+}
+
+/**
+ * 网卡链路信息接口
+ */
+export interface NetworkInterface {
+  name: string
+  operstate: string
+  link_up: boolean
+  speed: number | null
+  duplex: string | null
+  is_physical: boolean
+  expected_speed: number | null
+  below_expected_speed: boolean
+  link_drop_count: number
+  last_link_drop: number | null
+}
+
+export interface NetworkInfo {
+  interfaces: NetworkInterface[]
+  link_down_count: number
+  degraded_count: number
+}
+
+/**
+ * 网络可达性探测接口
+ */
+export type ProbeKind = 'tcp' | 'dns' | 'http'
+
+export interface ProbeTarget {
+  name: string
+  kind: ProbeKind
+  target: string
+}
+
+export interface ProbeResult {
+  name: string
+  kind: ProbeKind
+  target: string
+  reachable: boolean
+  latency_ms: number | null
+  packet_loss_percent: number
+  error: string | null
+}
+
+export interface ReachabilityInfo {
+  results: ProbeResult[]
+  internet_reachable: boolean
+  timestamp: number
+}
+
+/**
+ * 固定进程信息接口
+ */
+export interface ProcessSample {
+  timestamp: number
+  cpu_usage: number
+  memory: number
+}
+
+export interface PinnedProcess {
+  name: string
+  running: boolean
+  pids: number[]
+  cpu_usage: number
+  memory: number
+  history: ProcessSample[]
+}
+
+export interface ProcessInfo {
+  pinned: PinnedProcess[]
+}
+
+/**
+ * 磁盘 SMART 信息接口
+ */
+export interface SmartDevice {
+  device: string
+  device_type: string
+  protocol: string
+  model: string | null
+  serial: string | null
+  passed: boolean | null
+  temperature: number | null
+  power_on_hours: number | null
+  power_cycles: number | null
+  reallocated_sectors: number | null
+  pending_sectors: number | null
+  uncorrectable_sectors: number | null
+  media_errors: number | null
+  error_log_count: number | null
+  remaining_life_percent: number | null
+  warnings: string[]
+  error: string | null
+  standby: boolean
+}
+
+export interface SmartInfo {
+  available: boolean
+  devices: SmartDevice[]
+  updated_at: number | null
+}
+
+/**
+ * 电源、合盖与扩展坞状态接口
+ */
+export interface PowerStateEvent {
+  timestamp: number
+  state: 'ac' | 'lid' | 'dock'
+  value: boolean
+}
+
+export interface PowerStateInfo {
+  on_ac: boolean | null
+  lid_open: boolean | null
+  docked: boolean | null
+  events: PowerStateEvent[]
+}
+
+/**
+ * 访客模式状态接口
+ */
+export interface GuestModeStatus {
+  enabled: boolean
+  pin_set: boolean
+}
+
+/**
+ * 电池信息接口
+ */
+export interface Battery {
+  name: string
+  manufacturer: string | null
+  model: string | null
+  technology: string | null
+  charge_percent: number | null
+  status: string
+  charging: boolean
+  design_capacity_wh: number | null
+  full_capacity_wh: number | null
+  health_percent: number | null
+  discharge_rate_watts: number | null
+  time_remaining_minutes: number | null
+  cycle_count: number | null
+  warnings: string[]
+}
+
+export interface BatteryInfo {
+  batteries: Battery[]
+  low: boolean
+  degraded: boolean
+}
+
+/**
+ * 硬件清单接口
+ */
+export interface InventoryItem {
+  kind: 'disk' | 'dimm' | 'gpu' | 'sensor'
+  id: string
+  description: string
+}
+
+export interface InventoryChange {
+  timestamp: number
+  added: boolean
+  item: InventoryItem
+}
+
+export interface InventoryInfo {
+  items: InventoryItem[]
+  changes: InventoryChange[]
 }
 
 /**
@@ -78,8 +563,271 @@ export async function getDiskInfo(): Promise<DisksInfo> {
 }
 
 /**
- * 获取所有硬件信息（一次性获取）
+ * 获取资源压力信息
+ */
+export async function getPressureInfo(): Promise<PressureInfo> {
+  return await invoke('get_pressure_info')
+}
+
+/**
+ * 获取温度信息
+ */
+export async function getThermalInfo(): Promise<ThermalInfo> {
+  return await invoke('get_thermal_info')
+}
+
+/**
+ * 获取温度最高的 n 个传感器（默认 5 个，包含 thermal 温区与 hwmon 温度通道）
+ */
+export async function getHottestSensors(n?: number): Promise<HotSensor[]> {
+  return await invoke('get_hottest_sensors', { n })
+}
+
+/**
+ * 获取 GPU 信息
+ */
+export async function getGpuInfo(): Promise<GpuInfo> {
+  return await invoke('get_gpu_info')
+}
+
+/**
+ * 获取 hwmon 传感器芯片及驱动状态
+ */
+export async function getHwmonInfo(): Promise<HwmonInfo> {
+  return await invoke('get_hwmon_info')
+}
+
+/**
+ * 加载 Super-I/O 传感器驱动
+ */
+export async function loadSensorDriver(module: string): Promise<void> {
+  return await invoke('load_sensor_driver', { module })
+}
+
+/**
+ * 获取已保存的崩溃报告
+ */
+export async function getCrashReports(): Promise<CrashReport[]> {
+  return await invoke('get_crash_reports')
+}
+
+/**
+ * 清除已保存的崩溃报告
+ */
+export async function clearCrashReports(): Promise<void> {
+  return await invoke('clear_crash_reports')
+}
+
+/**
+ * 运行磁盘基准测试（path 为目标磁盘上的目录，默认使用临时目录）
+ */
+export async function runDiskBenchmark(path?: string): Promise<DiskBenchmarkResult> {
+  return await invoke('run_disk_benchmark', { path })
+}
+
+/**
+ * 获取最近一次磁盘基准测试结果
+ */
+export async function getLastDiskBenchmark(): Promise<DiskBenchmarkResult | null> {
+  return await invoke('get_last_disk_benchmark')
+}
+
+/**
+ * 运行压力测试（duration 单位为秒，范围 10-600）
+ */
+export async function runStressTest(duration: number): Promise<StressTestReport> {
+  return await invoke('run_stress_test', { duration })
+}
+
+/**
+ * 获取最近一次压力测试报告
+ */
+export async function getLastStressTest(): Promise<StressTestReport | null> {
+  return await invoke('get_last_stress_test')
+}
+
+/**
+ * 开始高频采集（1 秒间隔，minutes 范围 1-60）
+ */
+export async function startCapture(minutes: number): Promise<void> {
+  return await invoke('start_capture', { minutes })
+}
+
+/**
+ * 提前停止高频采集
+ */
+export async function stopCapture(): Promise<void> {
+  return await invoke('stop_capture')
+}
+
+/**
+ * 获取高频采集数据
+ */
+export async function getCapture(): Promise<CaptureData> {
+  return await invoke('get_capture')
+}
+
+/**
+ * 获取 CPU 调速器 / 电源计划
+ */
+export async function getPowerPlans(): Promise<PowerPlanInfo> {
+  return await invoke('get_power_plans')
+}
+
+/**
+ * 切换 CPU 调速器 / 电源计划（需要管理员权限）
+ */
+export async function setPowerPlan(id: string): Promise<void> {
+  return await invoke('set_power_plan', { id })
+}
+
+/**
+ * 获取仪表盘视图布局
+ */
+export async function getDashboardLayout(view: string): Promise<DashboardLayout | null> {
+  return await invoke('get_dashboard_layout', { view })
+}
+
+/**
+ * 保存仪表盘视图布局
+ */
+export async function saveDashboardLayout(layout: DashboardLayout): Promise<void> {
+  return await invoke('save_dashboard_layout', { layout })
+}
+
+/**
+ * 获取虚拟机信息 (libvirt)
+ */
+export async function getVmInfo(): Promise<VmInfo> {
+  return await invoke('get_vm_info')
+}
+
+/**
+ * 获取网卡链路状态与速率
+ */
+export async function getNetworkInfo(): Promise<NetworkInfo> {
+  return await invoke('get_network_info')
+}
+
+/**
+ * 设置网卡期望速率（Mbps，传 null 清除）
+ */
+export async function setExpectedLinkSpeed(iface: string, speed: number | null): Promise<void> {
+  return await invoke('set_expected_link_speed', { interface: iface, speed })
+}
+
+/**
+ * 探测配置目标的网络可达性
+ */
+export async function runReachabilityProbe(): Promise<ReachabilityInfo> {
+  return await invoke('run_reachability_probe')
+}
+
+/**
+ * 获取可达性探测目标
+ */
+export async function getReachabilityTargets(): Promise<ProbeTarget[]> {
+  return await invoke('get_reachability_targets')
+}
+
+/**
+ * 保存可达性探测目标
+ */
+export async function setReachabilityTargets(targets: ProbeTarget[]): Promise<void> {
+  return await invoke('set_reachability_targets', { targets })
+}
+
+/**
+ * 获取固定进程的 CPU / 内存占用
+ */
+export async function getProcessInfo(): Promise<ProcessInfo> {
+  return await invoke('get_process_info')
+}
+
+/**
+ * 设置需要跟踪的进程名称
+ */
+export async function setPinnedProcesses(names: string[]): Promise<void> {
+  return await invoke('set_pinned_processes', { names })
+}
+
+/**
+ * 获取磁盘 SMART 健康信息（缓存 5 分钟）
+ */
+export async function getSmartInfo(): Promise<SmartInfo> {
+  return await invoke('get_smart_info')
+}
+
+/**
+ * 获取交流电源、合盖与扩展坞状态及其变化记录
+ */
+export async function getPowerState(): Promise<PowerStateInfo> {
+  return await invoke('get_power_state')
+}
+
+/**
+ * 监听电源、合盖与扩展坞状态变化
+ *
+ * 后端每 2 秒采样一次，每次变化发出一个 power-state-changed 事件，返回取消监听的函数
+ */
+export async function onPowerStateChanged(
+  handler: (event: PowerStateEvent) => void
+): Promise<UnlistenFn> {
+  return await listen<PowerStateEvent>('power-state-changed', (event) => handler(event.payload))
+}
+
+/**
+ * 获取访客模式状态
+ */
+export async function getGuestMode(): Promise<GuestModeStatus> {
+  return await invoke('get_guest_mode')
+}
+
+/**
+ * 开启访客模式（首次开启需设置 4-12 位数字 PIN）
+ */
+export async function enableGuestMode(pin?: string): Promise<void> {
+  return await invoke('enable_guest_mode', { pin })
+}
+
+/**
+ * 输入 PIN 退出访客模式
+ */
+export async function disableGuestMode(pin: string): Promise<void> {
+  return await invoke('disable_guest_mode', { pin })
+}
+
+/**
+ * 获取电池信息
+ */
+export async function getBatteryInfo(): Promise<BatteryInfo> {
+  return await invoke('get_battery_info')
+}
+
+/**
+ * 获取硬件清单及本次运行期间的新增/消失记录（定期调用以检测变化）
+ */
+export async function getHardwareInventory(): Promise<InventoryInfo> {
+  return await invoke('get_hardware_inventory')
+}
+
+/**
+ * 获取磁盘汇总的排除规则
+ */
+export async function getDiskExclusions(): Promise<string[]> {
+  return await invoke('get_disk_exclusions')
+}
+
+/**
+ * 设置磁盘汇总的排除规则（以 / 或盘符如 D: 开头匹配挂载点，其余匹配文件系统类型，支持 * 和 ?）
+ */
+export async function setDiskExclusions(patterns: string[]): Promise<void> {
+  return await invoke('set_disk_exclusions', { patterns })
+}
+
+/**
+ * 获取所有硬件信息（一次性获取；传入 maxAgeMs 时该时长内返回缓存快照，不传则总是重新采集）
  */
-export async function getAllHardwareInfo(): Promise<AllHardwareInfo> {
-  return await invoke('get_all_hardware_info')
+export async function getAllHardwareInfo(maxAgeMs?: number): Promise<AllHardwareInfo> {
+  return await invoke('get_all_hardware_info', { maxAgeMs })
 }